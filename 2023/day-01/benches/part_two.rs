@@ -0,0 +1,13 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use aoc2023_day_01::{generate, part_two, part_two_naive, DigitLexicon};
+
+fn bench_part_two(c: &mut Criterion) {
+    let input = generate(0, 100);
+    let mut group = c.benchmark_group("part_two");
+    group.bench_function("naive", |b| b.iter(|| part_two_naive(black_box(&input))));
+    group.bench_function("automaton", |b| b.iter(|| part_two(black_box(&input), &DigitLexicon::english()).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_part_two);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+/// A DFA that finds a set of byte patterns anywhere in its input
+/// (Aho-Corasick), with every transition precomputed so that each input
+/// byte costs a single table lookup.
+#[derive(Debug, Clone)]
+pub struct Automaton {
+    delta: Vec<[u32; 256]>,
+    /// The longest pattern ending in each state, as `(value, length)`.
+    output: Vec<Option<(u32, usize)>>,
+    max_len: usize,
+}
+
+const NONE: u32 = u32::MAX;
+
+impl Automaton {
+    /// Builds the automaton from patterns and the values they stand for.
+    /// Empty patterns are ignored.
+    pub fn new<'a>(patterns: impl IntoIterator<Item = (&'a [u8], u32)>) -> Self {
+        // First a trie of the patterns, with missing edges left as NONE.
+        let mut delta = vec![[NONE; 256]];
+        let mut output = vec![None];
+        let mut max_len = 0;
+        for (pattern, value) in patterns.into_iter().filter(|(p, _)| !p.is_empty()) {
+            let mut state = 0;
+            for &b in pattern {
+                if delta[state][b as usize] == NONE {
+                    delta[state][b as usize] = delta.len() as u32;
+                    delta.push([NONE; 256]);
+                    output.push(None);
+                }
+                state = delta[state][b as usize] as usize;
+            }
+            // With duplicate patterns, the first one wins.
+            output[state].get_or_insert((value, pattern.len()));
+            max_len = max_len.max(pattern.len());
+        }
+
+        // Then fill in the missing edges breadth-first, following the
+        // failure link of each state: the state of its longest proper
+        // suffix that is also a prefix of some pattern.
+        let mut fail = vec![0; delta.len()];
+        let mut queue = std::collections::VecDeque::new();
+        for edge in delta[0].iter_mut() {
+            match *edge {
+                NONE => *edge = 0,
+                child => queue.push_back(child as usize),
+            }
+        }
+        while let Some(state) = queue.pop_front() {
+            if output[state].is_none() {
+                output[state] = output[fail[state]];
+            }
+            let fallback = delta[fail[state]];
+            for (edge, next) in delta[state].iter_mut().zip(fallback) {
+                match *edge {
+                    NONE => *edge = next,
+                    child => {
+                        fail[child as usize] = next as usize;
+                        queue.push_back(child as usize);
+                    },
+                }
+            }
+        }
+
+        Self { delta, output, max_len }
+    }
+
+    /// Returns the value of the match that starts first in `bytes`, or of
+    /// the longest one if several start at the same position.
+    pub fn find_first(&self, bytes: impl IntoIterator<Item = u8>) -> Option<u32> {
+        self.find(bytes).map(|found| found.value)
+    }
+
+    /// Like `find_first`, but also returns where the match is.
+    ///
+    /// Scanning stops as soon as no earlier-starting match is possible, so
+    /// for a match near the start only a few bytes are ever read.
+    pub fn find(&self, bytes: impl IntoIterator<Item = u8>) -> Option<Match> {
+        let mut state = 0;
+        let mut best: Option<Match> = None;
+        for (i, b) in bytes.into_iter().enumerate() {
+            // A match starting at or before `start` would have ended by now.
+            if best.is_some_and(|found| i + 1 > found.start + self.max_len) {
+                break;
+            }
+            state = self.delta[state][b as usize] as usize;
+            if let Some((value, len)) = self.output[state] {
+                let start = i + 1 - len;
+                let better = |found: Match| start < found.start || (start == found.start && len > found.end - found.start);
+                if best.is_none_or(better) {
+                    best = Some(Match { start, end: i + 1, value });
+                }
+            }
+        }
+        best
+    }
+}
+
+/// A pattern found by `Automaton::find`, at `start..end` in its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Match};
+
+    #[test]
+    fn test_find_first() {
+        let patterns: [(&[u8], u32); 4] = [(b"abcd", 1), (b"bc", 2), (b"c", 3), (b"xyz", 4)];
+        let automaton = Automaton::new(patterns);
+        // `bc` ends first, but `abcd` starts first.
+        assert_eq!(Some(1), automaton.find_first(*b"zabcd"));
+        assert_eq!(Some(2), automaton.find_first(*b"abcx"));
+        assert_eq!(Some(4), automaton.find_first(*b"xxyzc"));
+        assert_eq!(None, automaton.find_first(*b"ab"));
+        assert_eq!(Some(Match { start: 1, end: 4, value: 4 }), automaton.find(*b"xxyzc"));
+    }
+
+    #[test]
+    fn test_find_longest() {
+        let patterns: [(&[u8], u32); 3] = [(b"ab", 1), (b"abc", 2), (b"abcde", 3)];
+        let automaton = Automaton::new(patterns);
+        assert_eq!(Some(Match { start: 0, end: 3, value: 2 }), automaton.find(*b"abc"));
+        assert_eq!(Some(Match { start: 0, end: 5, value: 3 }), automaton.find(*b"abcdex"));
+        assert_eq!(Some(Match { start: 1, end: 4, value: 2 }), automaton.find(*b"xabcd"));
+        assert_eq!(Some(1), automaton.find_first(*b"abx"));
+    }
+}
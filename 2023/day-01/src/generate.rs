@@ -0,0 +1,46 @@
+use aoc_utils::Rng;
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Generates a valid input with `1000 * scale` lines of letters, digits
+/// and spelled-out digits, including overlapping ones such as `eightwo`.
+/// Every line has at least one digit, so both parts can solve it. The
+/// same seed and scale always give the same input.
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut result = String::new();
+    for _ in 0..1000 * scale {
+        let mut line = String::new();
+        let digit_at = rng.below(8);
+        for i in 0..rng.range(8..16) {
+            if i == digit_at || rng.chance(0.05) {
+                line.push(char::from(b'1' + rng.below(9) as u8));
+            } else if rng.chance(0.15) {
+                line.push_str(rng.choose::<&str>(&WORDS));
+            } else {
+                line.push(char::from(b'a' + rng.below(26) as u8));
+            }
+        }
+        result.push_str(&line);
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{part_two, part_two_naive, solve, DigitLexicon};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(1, 1);
+        assert_eq!(input, generate(1, 1));
+        let (one, two) = solve(&input);
+        assert!(one.is_ok() && two.is_ok());
+        for seed in 0..20 {
+            let input = generate(seed, 1);
+            assert_eq!(Ok(part_two_naive(&input)), part_two(&input, &DigitLexicon::english()));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use crate::automaton::{Automaton, Match};
+
+/// How spelled-out digits that share letters, such as `eightwo`, count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overlap {
+    /// Every spelling counts, even if it overlaps another: `eightwo` is
+    /// `8` then `2`. This is what the puzzle expects.
+    #[default]
+    Both,
+    /// Spellings are read left to right without sharing letters, the
+    /// longest one winning at each position: `eightwo` is just `8`.
+    Leftmost,
+}
+
+/// The words that stand for digits in part two, and how to match them.
+///
+/// The digits `1` to `9` are always recognised. `0` is only recognised
+/// once a word for zero is added, since the puzzle itself has none.
+///
+/// ```
+/// use aoc2023_day_01::{part_two, DigitLexicon, Overlap};
+///
+/// let lexicon = DigitLexicon::german().case_insensitive().with_overlap(Overlap::Leftmost);
+/// assert_eq!(part_two("Achteins\nzweinsieben", &lexicon), Ok(81 + 27));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DigitLexicon {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+    overlap: Overlap,
+}
+
+impl DigitLexicon {
+    /// A lexicon from `(word, value)` pairs, such as `("one", 1)`.
+    pub fn new<'a>(words: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Self {
+            words: words.into_iter().map(|(word, value)| (word.to_string(), value)).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// "one" to "nine", as in the puzzle.
+    pub fn english() -> Self {
+        Self::new(numbered(["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"]))
+    }
+
+    /// "eins" to "neun".
+    pub fn german() -> Self {
+        Self::new(numbered(["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"]))
+    }
+
+    /// Adds another word, such as `with_word("zero", 0)`.
+    pub fn with_word(mut self, word: &str, value: u32) -> Self {
+        self.words.push((word.to_string(), value));
+        self
+    }
+
+    /// Matches words regardless of the case of their ASCII letters.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Sets how overlapping spellings count, `Overlap::Both` by default.
+    pub fn with_overlap(mut self, overlap: Overlap) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Compiles the lexicon for scanning lines.
+    pub fn scanner(&self) -> DigitScanner {
+        let has_zero = self.words.iter().any(|&(_, value)| value == 0);
+        let digits = (if has_zero { 0 } else { 1 }..=9).map(|n| (n.to_string(), n));
+        let words = self.words.iter().map(|(word, value)| match self.case_insensitive {
+            true => (word.to_ascii_lowercase(), *value),
+            false => (word.clone(), *value),
+        });
+        let patterns: Vec<(Vec<u8>, u32)> = digits
+            .chain(words)
+            .map(|(pattern, value)| (pattern.into_bytes(), value))
+            .collect();
+        let reversed: Vec<(Vec<u8>, u32)> = patterns
+            .iter()
+            .map(|(pattern, value)| (pattern.iter().rev().copied().collect(), *value))
+            .collect();
+
+        DigitScanner {
+            forward: Automaton::new(patterns.iter().map(|(p, v)| (p.as_slice(), *v))),
+            backward: Automaton::new(reversed.iter().map(|(p, v)| (p.as_slice(), *v))),
+            case_insensitive: self.case_insensitive,
+            overlap: self.overlap,
+        }
+    }
+}
+
+fn numbered<const N: usize>(words: [&str; N]) -> impl Iterator<Item = (&str, u32)> {
+    words.into_iter().zip(1..)
+}
+
+/// Finds the first and the last digit of a line, whether written as a
+/// digit or spelled out.
+///
+/// One automaton scans forwards from the start of the line, and with
+/// `Overlap::Both` another one, built from the reversed spellings, scans
+/// backwards from the end. Each stops at its first match, so the middle
+/// of the line is never read. With `Overlap::Leftmost` the last digit
+/// depends on every match before it, so the whole line is read.
+#[derive(Debug, Clone)]
+pub struct DigitScanner {
+    forward: Automaton,
+    backward: Automaton,
+    case_insensitive: bool,
+    overlap: Overlap,
+}
+
+impl DigitScanner {
+    /// Returns the first and last digit of the line, or `None` if it
+    /// has none.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        match self.overlap {
+            Overlap::Both => {
+                let first = self.forward.find_first(self.fold(line.as_bytes().iter()))?;
+                let last = self.backward.find_first(self.fold(line.as_bytes().iter().rev()))?;
+                Some((first, last))
+            },
+            Overlap::Leftmost => {
+                let matches = self.matches(line);
+                Some((matches.first()?.value, matches.last()?.value))
+            },
+        }
+    }
+
+    /// Every digit of the line in order, as the overlap policy reads them.
+    /// With `Overlap::Both` this is the longest match at each position.
+    pub fn matches(&self, line: &str) -> Vec<Match> {
+        let bytes = line.as_bytes();
+        let mut matches = Vec::new();
+        let mut offset = 0;
+        while let Some(found) = self.forward.find(self.fold(bytes[offset..].iter())) {
+            let found = Match { start: offset + found.start, end: offset + found.end, value: found.value };
+            offset = match self.overlap {
+                Overlap::Both => found.start + 1,
+                Overlap::Leftmost => found.end,
+            };
+            matches.push(found);
+        }
+        matches
+    }
+
+    fn fold<'a>(&self, bytes: impl Iterator<Item = &'a u8> + 'a) -> impl Iterator<Item = u8> + 'a {
+        let case_insensitive = self.case_insensitive;
+        bytes.map(move |&b| if case_insensitive { b.to_ascii_lowercase() } else { b })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DigitLexicon, Overlap};
+
+    #[test]
+    fn test_overlap_both() {
+        let scanner = DigitLexicon::english().scanner();
+        assert_eq!(Some((8, 2)), scanner.first_and_last("eightwo"));
+        assert_eq!(Some((2, 8)), scanner.first_and_last("xtwone3fouroneight"));
+        assert_eq!(Some((7, 7)), scanner.first_and_last("7"));
+        assert_eq!(None, scanner.first_and_last("abc"));
+    }
+
+    #[test]
+    fn test_overlap_leftmost() {
+        let scanner = DigitLexicon::english().with_overlap(Overlap::Leftmost).scanner();
+        assert_eq!(Some((8, 8)), scanner.first_and_last("eightwo"));
+        assert_eq!(Some((2, 1)), scanner.first_and_last("xtwone3fouroneight"));
+        assert_eq!(Some((1, 3)), scanner.first_and_last("oneightwothree"));
+        assert_eq!(None, scanner.first_and_last("abc"));
+    }
+
+    #[test]
+    fn test_overlap_leftmost_prefix() {
+        // "sixteen" starts with "six", and the longer spelling wins.
+        let lexicon = DigitLexicon::english().with_word("sixteen", 7).with_overlap(Overlap::Leftmost);
+        assert_eq!(Some((7, 2)), lexicon.scanner().first_and_last("sixteentwo"));
+        assert_eq!(Some((6, 6)), lexicon.scanner().first_and_last("sixteeX"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let values = |lexicon: DigitLexicon, line| -> Vec<u32> {
+            lexicon.scanner().matches(line).iter().map(|found| found.value).collect()
+        };
+        assert_eq!(vec![1, 8, 2, 3], values(DigitLexicon::english(), "oneightwothree"));
+        assert_eq!(vec![1, 2, 3], values(DigitLexicon::english().with_overlap(Overlap::Leftmost), "oneightwothree"));
+    }
+
+    #[test]
+    fn test_zero_and_case() {
+        let scanner = DigitLexicon::english().scanner();
+        assert_eq!(Some((1, 1)), scanner.first_and_last("zeroOne1"));
+        assert_eq!(None, scanner.first_and_last("0"));
+
+        let scanner = DigitLexicon::english().with_word("zero", 0).case_insensitive().scanner();
+        assert_eq!(Some((0, 1)), scanner.first_and_last("ZeroOne"));
+        assert_eq!(Some((0, 0)), scanner.first_and_last("0"));
+    }
+
+    #[test]
+    fn test_german() {
+        let scanner = DigitLexicon::german().scanner();
+        assert_eq!(Some((1, 5)), scanner.first_and_last("xeinsfünfy"));
+        assert_eq!(Some((7, 1)), scanner.first_and_last("siebeneins"));
+        assert_eq!(None, scanner.first_and_last("one two"));
+    }
+}
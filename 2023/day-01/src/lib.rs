@@ -0,0 +1,143 @@
+use std::fs;
+use std::fmt::Write;
+use std::process;
+use aoc_utils::PuzzleError;
+
+mod automaton;
+mod generate;
+mod lexicon;
+
+pub use automaton::{Automaton, Match};
+pub use generate::generate;
+pub use lexicon::{DigitLexicon, DigitScanner, Overlap};
+
+pub fn run_all(file_path: &str) {
+    let input = fs::read_to_string(file_path).expect("failed to read input file");
+    let (one, two) = solve(&input);
+    if let (Err(err), Err(_)) = (&one, &two) {
+        eprintln!("{}", err.clone().with_file(file_path).render(&input));
+        process::exit(1);
+    }
+    println!("Day 01");
+    for (name, answer) in [("One", one), ("Two", two)] {
+        match answer {
+            Ok(answer) => println!("    Part {}: {}", name, answer),
+            Err(err) => {
+                println!("    Part {}: no answer", name);
+                eprintln!("{}", err.with_file(file_path).render(&input));
+            },
+        }
+    }
+}
+
+/// Solves each part for the given input. The parts read digits
+/// differently, so an input can be valid for only one of them, like the
+/// example of part two, and each part fails on its own.
+pub fn solve(input: &str) -> (Result<String, PuzzleError>, Result<String, PuzzleError>) {
+    let one = part_one(input).map(|answer| answer.to_string());
+    let two = part_two(input, &DigitLexicon::english()).map(|answer| answer.to_string());
+    (one, two)
+}
+
+pub fn part_one(input: &str) -> Result<u32, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+            let first = digits.next().ok_or_else(|| PuzzleError::new(i + 1, 1, "a line with at least one digit"))?;
+            Ok(first * 10 + digits.next_back().unwrap_or(first))
+        })
+        .sum()
+}
+
+pub fn part_two(input: &str, lexicon: &DigitLexicon) -> Result<u32, PuzzleError> {
+    let scanner = lexicon.scanner();
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (first, last) = scanner.first_and_last(line).ok_or_else(|| no_digit(i))?;
+            Ok(first * 10 + last)
+        })
+        .sum()
+}
+
+/// Describes how part two reads each line: the digits and words found,
+/// the first and last of them, and the value they make.
+pub fn explain(input: &str, lexicon: &DigitLexicon) -> Result<String, PuzzleError> {
+    let scanner = lexicon.scanner();
+    let mut result = String::new();
+    let mut total = 0;
+    for (i, line) in input.lines().enumerate() {
+        let (first, last) = scanner.first_and_last(line).ok_or_else(|| no_digit(i))?;
+        let found: Vec<String> = scanner
+            .matches(line)
+            .iter()
+            .map(|found| match &line[found.start..found.end] {
+                digit if digit.len() == 1 => digit.to_string(),
+                word => format!("{}={}", word, found.value),
+            })
+            .collect();
+        let value = first * 10 + last;
+        total += value;
+        writeln!(result, "{:>5}: {}", i + 1, line).unwrap();
+        writeln!(result, "       found {}", found.join(" ")).unwrap();
+        writeln!(result, "       first {}, last {}, value {}", first, last, value).unwrap();
+    }
+    writeln!(result, "Total: {}", total).unwrap();
+    Ok(result)
+}
+
+fn no_digit(line: usize) -> PuzzleError {
+    PuzzleError::new(line + 1, 1, "a line with at least one digit or spelled-out digit")
+}
+
+/// The original part two, which collects every digit of every line.
+/// Kept as a baseline for the benchmarks.
+pub fn part_two_naive(input: &str) -> u32 {
+    input
+        .lines()
+        .map(split_string_by_spellings)
+        .map(|digits| as_two_digit_number(&digits))
+        .sum()
+}
+
+const SPELLINGS: [(&str, &str); 9] = [
+    ("1", "one"),
+    ("2", "two"),
+    ("3", "three"),
+    ("4", "four"),
+    ("5", "five"),
+    ("6", "six"),
+    ("7", "seven"),
+    ("8", "eight"),
+    ("9", "nine"),
+];
+
+fn as_two_digit_number(digits: &[u32]) -> u32 {
+    let first = digits.iter().next().unwrap();
+    let last = digits.iter().next_back().unwrap();
+    first * 10 + last
+}
+
+fn split_string_by_spellings(input: & str) -> Vec<u32> {
+    let spellings = SPELLINGS;
+
+    let mut results = Vec::new();
+    let mut temp = String::new();
+
+    for ch in input.chars() {
+        temp.push(ch);
+        for (num, word) in spellings.iter() {
+            if temp.ends_with(num) || temp.ends_with(word) {
+                // We only want to add the digit spelling to the list.
+                results.push(num.parse::<u32>().unwrap());
+                // temp.clear();
+            }
+        }
+    }
+
+    results
+}
+
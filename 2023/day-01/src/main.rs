@@ -0,0 +1,56 @@
+fn main() {
+    aoc2023_day_01::run_all("input/2023/1.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::PuzzleError;
+    use aoc2023_day_01::{explain, part_one, part_two, solve, DigitLexicon};
+
+    #[test]
+    fn test_part_one() {
+        let input = include_str!("test1.txt");
+        assert_eq!(part_one(input).unwrap(), 142);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = include_str!("test2.txt");
+        assert_eq!(part_two(input, &DigitLexicon::english()).unwrap(), 281);
+    }
+
+    #[test]
+    fn test_missing_digit() {
+        let input = "1abc2\nabc\nthree";
+        assert_eq!(part_one(input), Err(PuzzleError::new(2, 1, "a line with at least one digit")));
+        assert_eq!(
+            part_two(input, &DigitLexicon::english()),
+            Err(PuzzleError::new(2, 1, "a line with at least one digit or spelled-out digit")),
+        );
+    }
+
+    #[test]
+    fn test_solve_parts_independently() {
+        let (one, two) = solve(include_str!("test2.txt"));
+        assert_eq!(Err(PuzzleError::new(2, 1, "a line with at least one digit")), one);
+        assert_eq!(Ok("281".to_string()), two);
+
+        let (one, two) = solve("abc");
+        assert!(one.is_err() && two.is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = explain("xtwone3four\neightwo", &DigitLexicon::english()).unwrap();
+        let expected = concat!(
+            "    1: xtwone3four\n",
+            "       found two=2 one=1 3 four=4\n",
+            "       first 2, last 4, value 24\n",
+            "    2: eightwo\n",
+            "       found eight=8 two=2\n",
+            "       first 8, last 2, value 82\n",
+            "Total: 106\n",
+        );
+        assert_eq!(expected, explanation);
+    }
+}
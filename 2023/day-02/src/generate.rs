@@ -0,0 +1,49 @@
+use std::fmt::Write;
+use aoc_utils::Rng;
+
+/// Generates a valid input with `100 * scale` games of one to six draws
+/// each. The same seed and scale always give the same input.
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut result = String::new();
+    for id in 1..=100 * scale {
+        let draws: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let n = rng.range(1..4) as usize;
+                let cubes: Vec<String> = colors[..n].iter().map(|color| format!("{} {}", rng.range(1..21), color)).collect();
+                cubes.join(", ")
+            })
+            .collect();
+        writeln!(result, "Game {}: {}", id, draws.join("; ")).unwrap();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::parse_strict;
+
+    #[test]
+    fn test_generate() {
+        for scale in [1, 3] {
+            let games = parse_strict(&generate(7, scale)).expect("failed to parse games");
+            assert_eq!(100 * scale, games.len());
+            assert!(games.iter().zip(1..).all(|(game, id)| game.id == id));
+
+            // Every game has one to six draws of one to three colours, and
+            // every number of draws shows up.
+            let mut draws = [false; 7];
+            for game in &games {
+                draws[game.sets.len()] = true;
+                for set in &game.sets {
+                    assert!((1..=3).contains(&set.colors().count()));
+                    assert!(set.colors().all(|color| (1..=20).contains(&set.get(color))));
+                }
+            }
+            assert_eq!([false, true, true, true, true, true, true], draws);
+        }
+    }
+}
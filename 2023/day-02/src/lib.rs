@@ -0,0 +1,319 @@
+use std::fs;
+use std::fmt;
+use std::collections::BTreeMap;
+use std::process;
+use std::str::FromStr;
+use aoc_utils::PuzzleError;
+use aoc_utils::parse::{Input, ParseError, PResult};
+use aoc_utils::parse::{map, newline, opt, pair, parse_all, preceded, separated1, space0, space1, tag, take_while, terminated, uint, word};
+
+mod generate;
+mod query;
+
+pub use generate::generate;
+pub use query::{Answer, Query};
+
+pub fn run_all(file_path: &str) {
+    let contents = fs::read_to_string(file_path).expect("failed to read input file");
+    let (one, two) = match solve(&contents) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.with_file(file_path).render(&contents));
+            process::exit(1);
+        },
+    };
+    println!("Day 02");
+    println!("    Part One: {}", one);
+    println!("    Part Two: {}", two);
+}
+
+/// Solves both parts for the given input, with the bag of the puzzle.
+pub fn solve(contents: &str) -> Result<(String, String), PuzzleError> {
+    solve_with_bag(contents, &default_bag())
+}
+
+/// Solves both parts for the given input and bag. Any malformed game or
+/// draw is an error, reported at the first one.
+pub fn solve_with_bag(contents: &str, bag: &Set) -> Result<(String, String), PuzzleError> {
+    let input = parse_strict(contents).map_err(|mut errors| errors.remove(0))?;
+    Ok((part_one(&input, bag).to_string(), part_two(&input, bag).to_string()))
+}
+
+/// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+pub fn default_bag() -> Set {
+    Set::default().with("red", 12).with("green", 13).with("blue", 14)
+}
+
+/// Parses a bag given as `red=12,green=13,blue=14`.
+pub fn parse_bag(s: &str) -> Result<Set, PuzzleError> {
+    parse_all(bag, s)
+}
+
+/// A comma-separated list of colours and counts, such as `red=12,green=13`.
+fn bag(input: Input<'_>) -> PResult<'_, Set> {
+    let cubes = pair(terminated(word, tag("=")), uint::<u64>);
+    map(separated1(cubes, preceded(tag(","), space0)), |cubes| {
+        cubes.into_iter().fold(Set::default(), |bag, (color, amount)| bag.with(color, amount))
+    })(input)
+}
+
+pub fn part_one(input: &[Game], bag: &Set) -> u64 {
+    input.iter().filter(|game| game.is_possible(bag)).map(|game| game.id).sum()
+}
+
+pub fn part_two(input: &[Game], bag: &Set) -> u64 {
+    input.iter().map(|game| game.power(bag)).sum()
+}
+
+/// Parses the games, silently skipping anything malformed. See
+/// [`parse_lenient`] for what was skipped.
+pub fn parse(input: &str) -> Vec<Game> {
+    parse_lenient(input).games
+}
+
+/// Parses the games, failing with every malformed game and draw.
+pub fn parse_strict(input: &str) -> Result<Vec<Game>, Vec<PuzzleError>> {
+    let parsed = parse_lenient(input);
+    match parsed.errors.is_empty() {
+        true => Ok(parsed.games),
+        false => Err(parsed.errors),
+    }
+}
+
+/// Parses the games, skipping malformed draws as well as games with a
+/// malformed header or without a single well-formed draw. Everything
+/// that was skipped is reported in `errors`.
+pub fn parse_lenient(input: &str) -> Parsed {
+    let mut parsed = Parsed::default();
+    let mut rest = Input::new(input);
+    let mut number = 1;
+    while !rest.is_empty() {
+        let (line, next) = line_text(rest).expect("a line always parses");
+        if !line.trim().is_empty() {
+            recover_game(input, rest, number, &mut parsed);
+        }
+        rest = next;
+        number += 1;
+    }
+    parsed
+}
+
+/// The games of an input, along with every malformed game or draw in it.
+#[derive(Debug, Default)]
+pub struct Parsed {
+    pub games: Vec<Game>,
+    /// In input order, with positions relative to the whole input.
+    pub errors: Vec<PuzzleError>,
+}
+
+/// Parses the line at `input`, line `number` of `src`, one draw at a
+/// time, so that a malformed draw does not hide the draws after it.
+fn recover_game(src: &str, input: Input<'_>, number: usize, parsed: &mut Parsed) {
+    let locate = |err: ParseError| err.locate_from(src, number, input.offset());
+    let header = terminated(preceded(pair(tag("Game"), space1), uint::<u64>), pair(tag(":"), space0));
+    let (id, mut rest) = match header(input) {
+        Ok(ok) => ok,
+        Err(err) => {
+            parsed.errors.push(locate(err));
+            return;
+        },
+    };
+
+    let mut sets = Vec::new();
+    loop {
+        match terminated(set, pair(space0, end_of_draw))(rest) {
+            Ok((set, next)) => {
+                sets.push(set);
+                rest = next;
+            },
+            Err(err) => {
+                parsed.errors.push(locate(err));
+                (_, rest) = take_while(|c| !matches!(c, ';' | '\r' | '\n'))(rest).expect("take_while cannot fail");
+            },
+        }
+        match preceded(tag(";"), space0)(rest) {
+            Ok((_, next)) => rest = next,
+            Err(_) => break,
+        }
+    }
+
+    if !sets.is_empty() {
+        parsed.games.push(Game { id, sets });
+    }
+}
+
+/// Checks, without consuming anything, that a draw ends here.
+fn end_of_draw(input: Input<'_>) -> PResult<'_, ()> {
+    match input.rest().chars().next() {
+        None | Some(';' | '\r' | '\n') => Ok(((), input)),
+        Some(_) => input.error("\";\" or end of line"),
+    }
+}
+
+/// The text of a line, without its line break.
+fn line_text(input: Input<'_>) -> PResult<'_, &str> {
+    terminated(take_while(|c| c != '\n'), opt(newline))(input)
+}
+
+/// Cubes by colour, such as a single draw or the contents of a bag.
+/// Colours that are not mentioned count as zero.
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Set {
+    cubes: BTreeMap<String, u64>,
+}
+
+impl Set {
+    /// Returns the set with `amount` cubes of the colour.
+    pub fn with(mut self, color: &str, amount: u64) -> Self {
+        self.cubes.insert(color.to_string(), amount);
+        self
+    }
+
+    pub fn get(&self, color: &str) -> u64 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// The colours mentioned in the set, in alphabetical order.
+    pub fn colors(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+
+    /// Returns true if the set is possible with the provided bag.
+    pub fn is_possible(&self, bag: &Set) -> bool {
+        self.cubes.iter().all(|(color, &amount)| amount <= bag.get(color))
+    }
+
+    /// The fewest cubes of each colour that make both sets possible.
+    pub fn union(&self, other: &Set) -> Set {
+        let mut result = self.clone();
+        for (color, &amount) in &other.cubes {
+            let entry = result.cubes.entry(color.clone()).or_default();
+            *entry = (*entry).max(amount);
+        }
+        result
+    }
+
+    /// The product of the number of cubes of each colour.
+    pub fn power(&self) -> u64 {
+        self.cubes.values().product()
+    }
+}
+
+impl fmt::Display for Set {
+    /// Formats the set like a draw, such as `6 blue, 2 green, 4 red`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self.cubes.iter().map(|(color, amount)| format!("{} {}", amount, color)).collect();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+impl FromStr for Set {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(set, s)
+    }
+}
+
+/// A comma-separated list of cube counts, such as `3 blue, 4 red`.
+fn set(input: Input<'_>) -> PResult<'_, Set> {
+    let cubes = pair(terminated(uint::<u64>, space1), word);
+    map(separated1(cubes, preceded(tag(","), space0)), |cubes| {
+        cubes.into_iter().fold(Set::default(), |set, (amount, color)| set.with(color, amount))
+    })(input)
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug)]
+pub struct Game {
+    id: u64,
+    sets: Vec<Set>,
+} 
+
+impl FromStr for Game {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(game, s)
+    }
+}
+
+/// A whole line, such as `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+fn game(input: Input<'_>) -> PResult<'_, Game> {
+    let id = preceded(pair(tag("Game"), space1), uint::<u64>);
+    let sets = separated1(set, preceded(tag(";"), space0));
+    map(pair(terminated(id, pair(tag(":"), space0)), sets), |(id, sets)| Game { id, sets })(input)
+}
+
+impl Game {
+    #[allow(dead_code)]
+    fn new(id: u64) -> Self {
+        Self{
+            id,
+            sets: Vec::new(),
+        }
+    }
+
+    /// Returns true if every draw of the game is possible with the bag.
+    pub fn is_possible(&self, bag: &Set) -> bool {
+        self.sets.iter().all(|set| set.is_possible(bag))
+    }
+
+    /// The fewest cubes of each colour the bag must have held.
+    pub fn minimum_bag(&self) -> Set {
+        self.sets.iter().fold(Set::default(), |bag, set| bag.union(set))
+    }
+
+    /// The power of the minimum bag. A colour of `bag` that the game never
+    /// shows needs zero cubes, which makes the power zero.
+    pub fn power(&self, bag: &Set) -> u64 {
+        let minimum = self.minimum_bag();
+        if bag.colors().any(|color| minimum.get(color) == 0) {
+            return 0;
+        }
+        minimum.power()
+    }
+}
+
+#[allow(dead_code)]
+fn parse2(input: &str) -> Vec<Game> {
+    let mut result = Vec::new();
+
+    for line in input.lines().filter_map(|line| non_empty_line(line)) {
+        let mut line = line.strip_prefix("Game ").expect("invalid beginning of line");
+
+        let id = {
+            let parts: Vec<&str> = line.splitn(2, ": ").collect();
+            line = parts.get(1).unwrap_or(&"");
+            parts.first().unwrap_or(&"0").parse::<u64>().expect("failed to parse integer id")
+        };
+
+        let mut game = Game::new(id);
+        for item in line.split(";") {
+            let mut bag = Set::default();
+
+            for cube in item.split(",") {
+                let parts: Vec<&str> = cube.split_whitespace().collect();
+                let amount = parts.first().unwrap_or(&"").parse::<u64>().expect("failed to parse integer");
+                let color = parts.get(1).unwrap_or(&"");
+
+                bag = bag.with(color, amount);
+            }
+            game.sets.push(bag);
+        }
+        result.push(game);
+    }
+
+    result
+}
+
+#[allow(dead_code)]
+fn non_empty_line(line: &str) -> Option<&str> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed)
+    }
+}
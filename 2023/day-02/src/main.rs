@@ -0,0 +1,70 @@
+fn main() {
+    aoc2023_day_02::run_all("input/2023/2.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::PuzzleError;
+    use aoc2023_day_02::{default_bag, parse, parse_bag, parse_lenient, parse_strict, part_one, part_two, solve};
+
+    const INPUT: &str = concat!(
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n",
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n",
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n",
+    );
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(INPUT);
+        assert_eq!(8, part_one(&input, &default_bag()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(INPUT);
+        assert_eq!(2286, part_two(&input, &default_bag()));
+    }
+
+    #[test]
+    fn test_other_colors() {
+        let input = parse(concat!(
+            "Game 1: 3 blue, 4 red; 2 yellow, 6 blue; 2 green\n",
+            "Game 2: 1 blue, 2 green, 1 yellow; 3 green, 4 blue, 1 red\n",
+            "Game 3: 8 green, 6 blue, 20 red\n",
+        ));
+        let bag = parse_bag("red=12, green=13,blue=14,yellow=1").unwrap();
+        assert_eq!(2, part_one(&input, &bag));
+        // Game 3 shows no yellow cubes, so its power is zero.
+        assert_eq!(96 + 12, part_two(&input, &bag));
+        // Colours missing from the bag still count towards the power.
+        assert_eq!(96 + 12 + 960, part_two(&input, &default_bag()));
+        assert!(parse_bag("red=12,green").is_err());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = concat!(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
+            "Game two: 1 blue, 2 green\n",
+            "Game 3: 8 green, 6 blue; 5 blue 4 red; 5 green, 1 red\n",
+            "\n",
+            "Game 4: 1 green, 3 red; ; 3 green, red\n",
+        );
+        let errors = vec![
+            PuzzleError::new(2, 6, "an integer"),
+            PuzzleError::new(3, 33, "\";\" or end of line"),
+            PuzzleError::new(5, 25, "an integer"),
+            PuzzleError::new(5, 36, "an integer"),
+        ];
+        assert_eq!(Err(errors.clone()), parse_strict(input).map(|games| games.len()));
+        assert_eq!(Err(errors[0].clone()), solve(input));
+
+        // The well-formed draws of games 3 and 4 are kept.
+        let parsed = parse_lenient(input);
+        assert_eq!(errors, parsed.errors);
+        assert_eq!(3, parsed.games.len());
+        assert_eq!(1 + 3 + 4, part_one(&parsed.games, &default_bag()));
+    }
+}
@@ -0,0 +1,371 @@
+use std::fmt;
+use std::str::FromStr;
+use aoc_utils::PuzzleError;
+use aoc_utils::parse::{Input, PResult};
+use aoc_utils::parse::{map, one_of, parse_all, preceded, space0, tag, terminated, uint, word};
+
+use crate::{bag, default_bag, Game, Set};
+
+/// A question about the games, such as:
+///
+/// - `games where max(red) > 10 and draws >= 3`, the ids of the games
+///   that match a condition,
+/// - `count where not possible`, how many games match,
+/// - `sum id where possible(red=12,green=13,blue=14)`, the `sum`, `min`
+///   or `max` of a value over the games that match,
+/// - `min bag of game 5`, the fewest cubes of each colour game 5 needs.
+///
+/// A value is an integer, `id`, `draws` (the number of draws), `power`
+/// (of the minimum bag, zero if the game never shows a colour of the bag
+/// of the puzzle or of `power(red=1,...)`), or the `max`, `min` or
+/// `total` of a colour over the draws of a game, as in `max(red)`.
+/// Conditions compare two values with `<`, `<=`, `>`, `>=`, `=` or `!=`,
+/// or check that a game is `possible` with the bag of the puzzle or with
+/// `possible(red=1,...)`. They combine with `not`, `and`, `or` and
+/// parentheses.
+///
+/// ```
+/// use aoc2023_day_02::{parse, Answer, Query};
+///
+/// let games = parse("Game 1: 3 blue, 4 red; 1 red\nGame 2: 20 red, 1 blue\n");
+/// let query: Query = "sum id where max(red) > 12".parse().unwrap();
+/// assert_eq!(Answer::Number(2), query.eval(&games));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query(Statement);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Statement {
+    Games(Option<Condition>),
+    Count(Option<Condition>),
+    Aggregate(Aggregate, Value, Option<Condition>),
+    MinimumBag(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Aggregate {
+    Sum,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Integer(u64),
+    Id,
+    Draws,
+    Power(Set),
+    Max(String),
+    Min(String),
+    Total(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    Compare(Value, Comparison, Value),
+    Possible(Set),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+/// The result of a query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    /// The ids of the matching games, in input order.
+    Games(Vec<u64>),
+    Number(u64),
+    Bag(Set),
+    /// The `min` or `max` of no games, or the bag of a game that does
+    /// not exist.
+    Nothing,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Games(ids) if ids.is_empty() => write!(f, "no games"),
+            Answer::Games(ids) => {
+                let ids: Vec<String> = ids.iter().map(u64::to_string).collect();
+                write!(f, "{}", ids.join(", "))
+            },
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Bag(bag) => write!(f, "{}", bag),
+            Answer::Nothing => write!(f, "none"),
+        }
+    }
+}
+
+impl Query {
+    pub fn eval(&self, games: &[Game]) -> Answer {
+        match &self.0 {
+            Statement::Games(condition) => Answer::Games(matching(games, condition).map(|game| game.id).collect()),
+            Statement::Count(condition) => Answer::Number(matching(games, condition).count() as u64),
+            Statement::Aggregate(aggregate, value, condition) => {
+                let values = matching(games, condition).map(|game| value.eval(game));
+                let result = match aggregate {
+                    Aggregate::Sum => Some(values.sum()),
+                    Aggregate::Min => values.min(),
+                    Aggregate::Max => values.max(),
+                };
+                result.map_or(Answer::Nothing, Answer::Number)
+            },
+            Statement::MinimumBag(id) => games
+                .iter()
+                .find(|game| game.id == *id)
+                .map_or(Answer::Nothing, |game| Answer::Bag(game.minimum_bag())),
+        }
+    }
+}
+
+fn matching<'a>(games: &'a [Game], condition: &'a Option<Condition>) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(move |game| condition.as_ref().is_none_or(|condition| condition.eval(game)))
+}
+
+impl Value {
+    fn eval(&self, game: &Game) -> u64 {
+        match self {
+            Value::Integer(n) => *n,
+            Value::Id => game.id,
+            Value::Draws => game.sets.len() as u64,
+            Value::Power(bag) => game.power(bag),
+            Value::Max(color) => counts(game, color).max().unwrap_or(0),
+            Value::Min(color) => counts(game, color).min().unwrap_or(0),
+            Value::Total(color) => counts(game, color).sum(),
+        }
+    }
+}
+
+/// The number of cubes of the colour in each draw of the game.
+fn counts<'a>(game: &'a Game, color: &'a str) -> impl Iterator<Item = u64> + 'a {
+    game.sets.iter().map(move |set| set.get(color))
+}
+
+impl Condition {
+    fn eval(&self, game: &Game) -> bool {
+        match self {
+            Condition::Compare(left, comparison, right) => {
+                let (left, right) = (left.eval(game), right.eval(game));
+                match comparison {
+                    Comparison::Less => left < right,
+                    Comparison::LessOrEqual => left <= right,
+                    Comparison::Greater => left > right,
+                    Comparison::GreaterOrEqual => left >= right,
+                    Comparison::Equal => left == right,
+                    Comparison::NotEqual => left != right,
+                }
+            },
+            Condition::Possible(bag) => game.is_possible(bag),
+            Condition::Not(condition) => !condition.eval(game),
+            Condition::And(left, right) => left.eval(game) && right.eval(game),
+            Condition::Or(left, right) => left.eval(game) || right.eval(game),
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(map(statement, Query), s)
+    }
+}
+
+fn statement(input: Input<'_>) -> PResult<'_, Statement> {
+    let (_, start) = space0(input)?;
+    let Ok((first, rest)) = word(start) else {
+        return start.error("\"games\", \"count\", \"sum\", \"min\" or \"max\"");
+    };
+    match first {
+        "games" => map(filter, Statement::Games)(rest),
+        "count" => map(filter, Statement::Count)(rest),
+        "min" if keyword("bag")(rest).is_ok() => {
+            let of_game = preceded(keyword("bag"), preceded(keyword("of"), keyword("game")));
+            map(preceded(of_game, token(uint::<u64>)), Statement::MinimumBag)(rest)
+        },
+        "sum" | "min" | "max" => {
+            let aggregate = match first {
+                "sum" => Aggregate::Sum,
+                "min" => Aggregate::Min,
+                _ => Aggregate::Max,
+            };
+            let (value, rest) = value(rest)?;
+            map(filter, move |condition| Statement::Aggregate(aggregate, value.clone(), condition))(rest)
+        },
+        _ => start.error("\"games\", \"count\", \"sum\", \"min\" or \"max\""),
+    }
+}
+
+/// An optional `where` clause.
+fn filter(input: Input<'_>) -> PResult<'_, Option<Condition>> {
+    match keyword("where")(input) {
+        Ok((_, rest)) => map(condition, Some)(rest),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// Conditions joined by `or`, which binds less tightly than `and`.
+fn condition(input: Input<'_>) -> PResult<'_, Condition> {
+    let (mut result, mut rest) = conjunction(input)?;
+    while let Ok((_, after)) = keyword("or")(rest) {
+        let (right, after) = conjunction(after)?;
+        result = Condition::Or(Box::new(result), Box::new(right));
+        rest = after;
+    }
+    Ok((result, rest))
+}
+
+fn conjunction(input: Input<'_>) -> PResult<'_, Condition> {
+    let (mut result, mut rest) = factor(input)?;
+    while let Ok((_, after)) = keyword("and")(rest) {
+        let (right, after) = factor(after)?;
+        result = Condition::And(Box::new(result), Box::new(right));
+        rest = after;
+    }
+    Ok((result, rest))
+}
+
+fn factor(input: Input<'_>) -> PResult<'_, Condition> {
+    if let Ok((_, rest)) = keyword("not")(input) {
+        return map(factor, |condition| Condition::Not(Box::new(condition)))(rest);
+    }
+    if let Ok((_, rest)) = token(tag("("))(input) {
+        return terminated(condition, token(tag(")")))(rest);
+    }
+    if let Ok((_, rest)) = keyword("possible")(input) {
+        return match token(tag("("))(rest) {
+            Ok((_, rest)) => map(terminated(token(bag), token(tag(")"))), Condition::Possible)(rest),
+            Err(_) => Ok((Condition::Possible(default_bag()), rest)),
+        };
+    }
+
+    let operators = one_of(&["<=", ">=", "!=", "<", ">", "="]);
+    let (left, rest) = value(input)?;
+    let (operator, rest) = token(operators)(rest)?;
+    let (right, rest) = value(rest)?;
+    let comparison = match operator {
+        "<" => Comparison::Less,
+        "<=" => Comparison::LessOrEqual,
+        ">" => Comparison::Greater,
+        ">=" => Comparison::GreaterOrEqual,
+        "=" => Comparison::Equal,
+        _ => Comparison::NotEqual,
+    };
+    Ok((Condition::Compare(left, comparison, right), rest))
+}
+
+fn value(input: Input<'_>) -> PResult<'_, Value> {
+    const EXPECTED: &str = "an integer, \"id\", \"draws\", \"power\", \"max(colour)\", \"min(colour)\" or \"total(colour)\"";
+
+    let (_, start) = space0(input)?;
+    if let Ok((n, rest)) = uint::<u64>(start) {
+        return Ok((Value::Integer(n), rest));
+    }
+    let Ok((name, rest)) = word(start) else {
+        return start.error(EXPECTED);
+    };
+    match name {
+        "id" => Ok((Value::Id, rest)),
+        "draws" => Ok((Value::Draws, rest)),
+        "power" => match token(tag("("))(rest) {
+            Ok((_, rest)) => map(terminated(token(bag), token(tag(")"))), Value::Power)(rest),
+            Err(_) => Ok((Value::Power(default_bag()), rest)),
+        },
+        "max" | "min" | "total" => {
+            let color = preceded(token(tag("(")), terminated(token(word), token(tag(")"))));
+            map(color, |color| match name {
+                "max" => Value::Max(color.to_string()),
+                "min" => Value::Min(color.to_string()),
+                _ => Value::Total(color.to_string()),
+            })(rest)
+        },
+        _ => start.error(EXPECTED),
+    }
+}
+
+/// The whole word `k`, after any spaces.
+fn keyword<'a>(k: &'static str) -> impl Fn(Input<'a>) -> PResult<'a, ()> {
+    move |input: Input<'a>| {
+        let (_, start) = space0(input)?;
+        match word(start) {
+            Ok((w, rest)) if w == k => Ok(((), rest)),
+            _ => start.error(format!("{:?}", k)),
+        }
+    }
+}
+
+/// `p`, after any spaces.
+fn token<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, T>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    preceded(space0, p)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::PuzzleError;
+    use super::{Answer, Query};
+    use crate::{default_bag, parse, part_two, Set};
+
+    const INPUT: &str = concat!(
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
+        "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n",
+        "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n",
+        "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n",
+        "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n",
+    );
+
+    fn eval(query: &str) -> Answer {
+        query.parse::<Query>().expect("failed to parse query").eval(&parse(INPUT))
+    }
+
+    #[test]
+    fn test_eval() {
+        assert_eq!(Answer::Games(vec![3, 4]), eval("games where max(red) > 10 and draws >= 3"));
+        assert_eq!(Answer::Number(8), eval("sum id where possible(red=12,green=13,blue=14)"));
+        assert_eq!(Answer::Number(8), eval("sum id where possible"));
+        assert_eq!(Answer::Number(2), eval("count where not possible"));
+        assert_eq!(Answer::Number(2286), eval("sum power"));
+        assert_eq!(Answer::Number(1560), eval("max power where (id = 1 or id = 3) and total(blue) != 0"));
+        assert_eq!(Answer::Games(vec![1, 2, 3, 5]), eval("games where min(red) <= 1 or power < 50"));
+        assert_eq!(Answer::Nothing, eval("min id where id > 5"));
+        assert_eq!(Answer::Bag(Set::default().with("blue", 2).with("green", 3).with("red", 6)), eval("min bag of game 5"));
+        assert_eq!(Answer::Nothing, eval(" min bag of game 6 "));
+        assert_eq!("6 blue, 2 green, 4 red", eval("min bag of game 1").to_string());
+        assert_eq!("1, 2, 5", eval("games where possible").to_string());
+    }
+
+    #[test]
+    fn test_eval_power() {
+        // Game 1 never shows a blue cube, so its power is zero, as in part two.
+        let games = parse(concat!(
+            "Game 1: 3 red; 2 green\n",
+            "Game 2: 1 red, 2 green, 3 blue\n",
+        ));
+        let eval = |query: &str| query.parse::<Query>().expect("failed to parse query").eval(&games);
+        assert_eq!(Answer::Number(part_two(&games, &default_bag())), eval("sum power"));
+        assert_eq!(Answer::Number(6), eval("sum power"));
+        assert_eq!(Answer::Games(vec![1]), eval("games where power = 0"));
+        assert_eq!(Answer::Number(12), eval("sum power(red=1,green=1)"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |query: &str| query.parse::<Query>().expect_err("expected a malformed query");
+        assert_eq!(PuzzleError::new(1, 1, "\"games\", \"count\", \"sum\", \"min\" or \"max\""), error("average id"));
+        assert_eq!(PuzzleError::new(1, 22, "one of \"<=\", \">=\", \"!=\", \"<\", \">\", \"=\""), error("games where max(red) 10"));
+        assert_eq!(PuzzleError::new(1, 26, "an integer"), error("count where possible(red=)"));
+        assert_eq!(PuzzleError::new(1, 9, "\"of\""), error("min bag game 5"));
+        assert_eq!(PuzzleError::new(1, 30, "\")\""), error("games where (id = 1 or id = 2"));
+    }
+}
@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use aoc2023_day_03::{generate, part_one, part_two, Graph, Rules, Stream};
+
+fn bench_parts(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parts");
+    for scale in [1, 4, 8] {
+        let input = generate(0, scale);
+        let graph = input.parse::<Graph>().expect("failed to parse input");
+        let size = format!("{0}x{0}", 140 * scale);
+        group.bench_with_input(BenchmarkId::new("parse", &size), &input, |b, input| {
+            b.iter(|| black_box(input).parse::<Graph>())
+        });
+        group.bench_with_input(BenchmarkId::new("part_one", &size), &graph, |b, graph| b.iter(|| part_one(black_box(graph), &Rules::default())));
+        group.bench_with_input(BenchmarkId::new("part_two", &size), &graph, |b, graph| b.iter(|| part_two(black_box(graph), &Rules::default())));
+        group.bench_with_input(BenchmarkId::new("stream", &size), &input, |b, input| {
+            b.iter(|| {
+                let mut stream = Stream::new();
+                for line in black_box(input).lines() {
+                    stream.push(line).expect("failed to parse line");
+                }
+                stream.finish()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parts);
+criterion_main!(benches);
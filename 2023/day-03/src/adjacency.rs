@@ -0,0 +1,188 @@
+use std::fmt::Write;
+use aoc_utils::Point;
+
+use crate::{Graph, Node, Rules};
+
+/// A symbol of the schematic, and where it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub point: Point,
+    pub char: char,
+}
+
+/// The bipartite graph of symbols and numbers, with an edge between
+/// each symbol and every number adjacent to it under the rules.
+///
+/// ```
+/// use aoc2023_day_03::{Adjacency, Graph, Rules};
+///
+/// let graph: Graph = "12.\n.#.\n.34\n..5\n".parse().unwrap();
+/// let adjacency = Adjacency::new(&graph, &Rules::default());
+/// assert_eq!(vec![2], adjacency.symbols().map(|(_, degree)| degree).collect::<Vec<_>>());
+/// assert_eq!(vec![5], adjacency.isolated_numbers().map(|node| node.value()).collect::<Vec<_>>());
+/// ```
+#[derive(Debug)]
+pub struct Adjacency<'a> {
+    graph: &'a Graph,
+    symbols: Vec<Symbol>,
+    /// The nodes adjacent to each symbol.
+    symbol_edges: Vec<Vec<usize>>,
+    /// The symbols adjacent to each node.
+    node_edges: Vec<Vec<usize>>,
+}
+
+impl<'a> Adjacency<'a> {
+    pub fn new(graph: &'a Graph, rules: &Rules) -> Self {
+        let mut symbols = Vec::new();
+        let mut symbol_edges = Vec::new();
+        let mut node_edges = vec![Vec::new(); graph.nodes.len()];
+        for ((row, col), &c) in graph.chars.iter() {
+            if !rules.is_symbol(c) {
+                continue;
+            }
+            let point = Point::from((row, col));
+            let mut nodes: Vec<usize> = rules.neighbors(point).filter_map(|nbor| graph.get_node_at(nbor)).collect();
+            nodes.sort_unstable();
+            nodes.dedup();
+            for &node in &nodes {
+                node_edges[node].push(symbols.len());
+            }
+            symbols.push(Symbol { point, char: c });
+            symbol_edges.push(nodes);
+        }
+        Self { graph, symbols, symbol_edges, node_edges }
+    }
+
+    /// Every symbol, in reading order, with its degree.
+    pub fn symbols(&self) -> impl Iterator<Item = (&Symbol, usize)> {
+        self.symbols.iter().zip(&self.symbol_edges).map(|(symbol, nodes)| (symbol, nodes.len()))
+    }
+
+    /// The numbers adjacent to the symbol at the point.
+    pub fn numbers_at(&self, point: Point) -> Vec<&'a Node> {
+        let graph = self.graph;
+        self.symbols
+            .iter()
+            .position(|symbol| symbol.point == point)
+            .map(|symbol| self.symbol_edges[symbol].iter().map(|&node| &graph.nodes[node]).collect())
+            .unwrap_or_default()
+    }
+
+    /// The numbers adjacent to more than one symbol, with those symbols.
+    pub fn shared_numbers(&self) -> impl Iterator<Item = (&'a Node, Vec<&Symbol>)> {
+        self.nodes()
+            .filter(|(_, symbols)| symbols.len() > 1)
+            .map(|(node, symbols)| (node, symbols.iter().map(|&symbol| &self.symbols[symbol]).collect()))
+    }
+
+    /// The numbers adjacent to no symbol at all.
+    pub fn isolated_numbers(&self) -> impl Iterator<Item = &'a Node> + '_ {
+        self.nodes().filter(|(_, symbols)| symbols.is_empty()).map(|(node, _)| node)
+    }
+
+    /// Renders the graph in the Graphviz DOT language. Symbols are boxes
+    /// and numbers are ellipses, both labelled with their position.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph schematic {\n");
+        for (i, symbol) in self.symbols.iter().enumerate() {
+            let label = escape(&symbol.char.to_string());
+            writeln!(dot, "    s{} [shape=box, label=\"{}\\n{}\"];", i, label, symbol.point).unwrap();
+        }
+        for (i, node) in self.graph.nodes.iter().enumerate() {
+            writeln!(dot, "    n{} [label=\"{}\\n{}\"];", i, node.value, node.points[0]).unwrap();
+        }
+        for (i, nodes) in self.symbol_edges.iter().enumerate() {
+            for node in nodes {
+                writeln!(dot, "    s{} -- n{};", i, node).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = (&'a Node, &Vec<usize>)> {
+        self.graph.nodes.iter().zip(&self.node_edges)
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::Point;
+    use super::Adjacency;
+    use crate::{Graph, Neighborhood, Node, Rules};
+
+    const INPUT: &str = concat!(
+        "467..114..\n",
+        "...*......\n",
+        "..35..633.\n",
+        "......#...\n",
+        "617*......\n",
+        ".....+.58.\n",
+        "..592.....\n",
+        "......755.\n",
+        "...$.*....\n",
+        ".664.598..\n",
+    );
+
+    fn values<'a>(nodes: impl IntoIterator<Item = &'a Node>) -> Vec<u64> {
+        nodes.into_iter().map(|node| node.value()).collect()
+    }
+
+    #[test]
+    fn test_degrees() {
+        let graph = INPUT.parse::<Graph>().unwrap();
+        let adjacency = Adjacency::new(&graph, &Rules::default());
+        let degrees: Vec<(char, usize)> = adjacency.symbols().map(|(symbol, degree)| (symbol.char, degree)).collect();
+        assert_eq!(vec![('*', 2), ('#', 1), ('*', 1), ('+', 1), ('$', 1), ('*', 2)], degrees);
+        assert_eq!(vec![467, 35], values(adjacency.numbers_at(Point::new(1, 3))));
+        assert!(adjacency.numbers_at(Point::new(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_isolated() {
+        let graph = INPUT.parse::<Graph>().unwrap();
+        let adjacency = Adjacency::new(&graph, &Rules::default());
+        assert_eq!(vec![114, 58], values(adjacency.isolated_numbers()));
+
+        let adjacency = Adjacency::new(&graph, &Rules::default().with_neighborhood(Neighborhood::Four));
+        assert_eq!(vec![467, 114, 58, 592, 755], values(adjacency.isolated_numbers()));
+    }
+
+    #[test]
+    fn test_shared() {
+        // The 5 touches both symbols, and the 7 only touches the `#`
+        // diagonally.
+        let graph = "*..\n.5.\n..#\n.7.\n".parse::<Graph>().unwrap();
+        let adjacency = Adjacency::new(&graph, &Rules::default());
+        let shared: Vec<(u64, Vec<char>)> = adjacency
+            .shared_numbers()
+            .map(|(node, symbols)| (node.value(), symbols.iter().map(|symbol| symbol.char).collect()))
+            .collect();
+        assert_eq!(vec![(5, vec!['*', '#'])], shared);
+        assert!(adjacency.isolated_numbers().next().is_none());
+
+        let adjacency = Adjacency::new(&graph, &Rules::default().with_neighborhood(Neighborhood::Four));
+        assert!(adjacency.shared_numbers().next().is_none());
+        assert_eq!(vec![5, 7], values(adjacency.isolated_numbers()));
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = "12.\n.\"4\n".parse::<Graph>().unwrap();
+        let adjacency = Adjacency::new(&graph, &Rules::default());
+        let expected = concat!(
+            "graph schematic {\n",
+            "    s0 [shape=box, label=\"\\\"\\n(1, 1)\"];\n",
+            "    n0 [label=\"12\\n(0, 0)\"];\n",
+            "    n1 [label=\"4\\n(1, 2)\"];\n",
+            "    s0 -- n0;\n",
+            "    s0 -- n1;\n",
+            "}\n",
+        );
+        assert_eq!(expected, adjacency.to_dot());
+    }
+}
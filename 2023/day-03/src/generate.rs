@@ -0,0 +1,68 @@
+use aoc_utils::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@%&=-";
+
+/// Generates a valid schematic of `140 * scale` by `140 * scale` cells,
+/// the size of the official input at a scale of one. The same seed and
+/// scale always give the same input.
+pub fn generate(seed: u64, scale: usize) -> String {
+    generate_sized(seed, 140 * scale, 140 * scale)
+}
+
+/// Like [`generate`], but with `width` columns and `height` rows.
+pub fn generate_sized(seed: u64, width: usize, height: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut result = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        let mut line = Vec::with_capacity(width);
+        while line.len() < width {
+            if rng.chance(0.12) {
+                // A number of one to three digits, followed by a dot so
+                // that it does not run into the next one.
+                let number = rng.range(1..1000).to_string();
+                line.extend(number.bytes().take(width - line.len()));
+                if line.len() < width {
+                    line.push(b'.');
+                }
+            } else if rng.chance(0.06) {
+                line.push(*rng.choose(SYMBOLS));
+            } else {
+                line.push(b'.');
+            }
+        }
+        result.push_str(std::str::from_utf8(&line).unwrap());
+        result.push('\n');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generate_sized, SYMBOLS};
+    use crate::Graph;
+
+    #[test]
+    fn test_generate() {
+        for scale in [1, 2] {
+            let input = generate(7, scale);
+            let size = 140 * scale;
+            assert_eq!(size, input.lines().count());
+            assert!(input.lines().all(|line| line.len() == size));
+            assert!(input.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b'\n' || SYMBOLS.contains(&b)));
+
+            // Numbers never run into each other.
+            let graph = input.parse::<Graph>().expect("failed to parse schematic");
+            assert!(graph.nodes.iter().all(|node| node.points().len() <= 3 && node.value() < 1000));
+        }
+    }
+
+    #[test]
+    fn test_generate_sized() {
+        for (width, height) in [(500, 3), (1, 40), (140, 140)] {
+            let input = generate_sized(3, width, height);
+            assert_eq!(height, input.lines().count());
+            assert!(input.lines().all(|line| line.len() == width));
+        }
+        assert_eq!(generate(3, 1), generate_sized(3, 140, 140));
+    }
+}
@@ -0,0 +1,186 @@
+
+/* I'm not proud of this code.
+ *
+ * I believe this question has exposed some pretty
+ * major holes in my understanding of graph theory,
+ * and made me realized I have a lot to learn.
+ *
+ */
+
+use std::fs;
+use std::process;
+use std::str::FromStr;
+use std::hash::Hash;
+use std::collections::HashSet;
+use aoc_utils::{Grid, Point, PuzzleError};
+
+mod adjacency;
+mod generate;
+mod rules;
+mod stream;
+
+pub use adjacency::{Adjacency, Symbol};
+pub use generate::{generate, generate_sized};
+pub use rules::{Combine, Neighborhood, Rules, Symbols};
+pub use stream::{ReadError, Stream};
+
+pub fn run_all(file_path: &str) {
+    let input = fs::read_to_string(file_path).expect("failed to read input file");
+    let (one, two) = match solve(&input) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.with_file(file_path).render(&input));
+            process::exit(1);
+        },
+    };
+    println!("Day 03");
+    println!("    Part One: {}", one);
+    println!("    Part Two: {}", two);
+}
+
+/// Solves both parts for the given input.
+pub fn solve(input: &str) -> Result<(String, String), PuzzleError> {
+    let graph = input.parse::<Graph>()?;
+    let rules = Rules::default();
+    Ok((part_one(&graph, &rules).to_string(), part_two(&graph, &rules).to_string()))
+}
+
+pub fn part_one(graph: &Graph, rules: &Rules) -> u64 {
+    graph.nodes
+        .iter()
+        .filter(|node| node.has_symbol_neighbor(graph, rules))
+        .map(|node| node.value)
+        .sum()
+}
+
+pub fn part_two(graph: &Graph, rules: &Rules) -> u64 {
+    let mut sum = 0;
+    for (index, x) in graph.chars.iter() {
+        if rules.is_gear(*x) {
+            let all_points = get_matching_neighbors(&graph.chars, rules, index.into(), char::is_numeric);
+
+            let points = all_points
+                .iter()
+                .filter_map(|&point| graph.get_node_at(point));
+
+            let values: Vec<_> = unique(points).map(|node| graph.nodes[node].value).collect();
+
+            if let Some(ratio) = rules.ratio(&values) {
+                sum += ratio;
+            }
+        }
+    }
+    sum
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug)]
+pub struct Node {
+    points: Vec<Point>,
+    value: u64,
+}
+
+impl Node {
+    fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            value: 0,
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// The cells the number covers, left to right.
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    fn has_symbol_neighbor(&self, graph: &Graph, rules: &Rules) -> bool {
+        for point in &self.points {
+            if !get_matching_neighbors(&graph.chars, rules, *point, |c| rules.is_symbol(c)).is_empty() {
+                return true;
+            }
+        }
+        false
+    }
+
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug)]
+pub struct Graph {
+    chars: Grid<char>,
+    nodes: Vec<Node>,
+    /// The node covering each cell, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    index: Grid<Option<usize>>,
+}
+
+impl Graph {
+    fn get_node_at(&self, point: Point) -> Option<usize> {
+        self.index.at(point).copied().flatten()
+    }
+}
+
+fn get_matching_neighbors<F> (grid: &Grid<char>, rules: &Rules, point: Point, predicate: F) -> Vec<Point>
+    where F: Fn(char) -> bool
+{
+    rules.neighbors(point)
+        .filter(|&nbor| grid.at(nbor).is_some_and(|&c| predicate(c)))
+        .collect()
+}
+
+impl FromStr for Graph {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.parse::<Grid<char>>()?;
+        let mut nodes = Vec::new();
+        let mut index = Grid::new(chars.rows(), chars.cols(), None);
+        // The line number and text of each row, to point errors at.
+        let texts = s.lines().enumerate().filter(|(_, text)| !text.trim().is_empty());
+        for ((row, line), (i, text)) in chars.iter_rows().enumerate().zip(texts) {
+            let mut col = 0;
+            while col < line.len() {
+                if line[col].is_ascii_digit() {
+                    let mut node = Node::new();
+                    let start = col;
+                    while let Some(digit) = line.get(col).and_then(|c| c.to_digit(10)) {
+                        node.value = push_digit(node.value, digit).ok_or_else(|| too_large(i + 1, text, start))?;
+                        node.points.push((row, col).into());
+                        index[(row, col)] = Some(nodes.len());
+                        col += 1;
+                    }
+                    nodes.push(node);
+                } else {
+                    col += 1;
+                }
+            }
+        }
+        Ok(Graph { chars, nodes, index })
+    }
+}
+
+/// Appends a decimal digit to `value`, if the result fits in a `u64`.
+fn push_digit(value: u64, digit: u32) -> Option<u64> {
+    value.checked_mul(10)?.checked_add(u64::from(digit))
+}
+
+/// The error for a number too large for a `u64`, starting at column
+/// `col` of the trimmed `text` of a line.
+fn too_large(line: usize, text: &str, col: usize) -> PuzzleError {
+    let indent = text.chars().count() - text.trim_start().chars().count();
+    PuzzleError::new(line, indent + col + 1, "a number that fits in u64")
+}
+
+fn unique<T, I>(iter: I) -> impl Iterator<Item = T>
+where
+    T: Hash + Eq + Clone,
+    I: Iterator<Item = T>,
+{
+    let mut seen = HashSet::new();
+    iter.filter(move |item| seen.insert(item.clone()))
+}
+
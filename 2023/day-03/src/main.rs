@@ -0,0 +1,50 @@
+fn main() {
+    aoc2023_day_03::run_all("input/2023/3.txt");
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023_day_03::{Combine, Graph, Neighborhood, Rules, Symbols, part_one, part_two};
+
+    const INPUT: &str = concat!(
+        "467..114..\n",
+        "...*......\n",
+        "..35..633.\n",
+        "......#...\n",
+        "617*......\n",
+        ".....+.58.\n",
+        "..592.....\n",
+        "......755.\n",
+        "...$.*....\n",
+        ".664.598..\n",
+    );
+
+    #[test]
+    fn test_part_one() {
+        let graph = INPUT.parse::<Graph>().expect("failed to parse input");
+        assert_eq!(4361, part_one(&graph, &Rules::default()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let graph = INPUT.parse::<Graph>().expect("failed to parse input");
+        assert_eq!(467835, part_two(&graph, &Rules::default()));
+    }
+
+    #[test]
+    fn test_rules() {
+        let graph = INPUT.parse::<Graph>().expect("failed to parse input");
+
+        let only_stars = Rules::default().with_symbols(Symbols::Only("*".to_string()));
+        assert_eq!(467 + 35 + 617 + 755 + 598, part_one(&graph, &only_stars));
+
+        let four = Rules::default().with_neighborhood(Neighborhood::Four);
+        assert_eq!(35 + 633 + 617 + 664 + 598, part_one(&graph, &four));
+
+        let sum = Rules::default().with_combine(Combine::Sum);
+        assert_eq!(467 + 35 + 755 + 598, part_two(&graph, &sum));
+
+        let one_or_two = Rules::default().with_gear_numbers(1..=2);
+        assert_eq!(467835 + 617, part_two(&graph, &one_or_two));
+    }
+}
@@ -0,0 +1,127 @@
+use std::ops::RangeInclusive;
+use aoc_utils::{Direction, Point};
+
+/// Which characters are symbols. Digits never are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Symbols {
+    /// Every character except the listed ones.
+    AllExcept(String),
+    /// Only the listed characters.
+    Only(String),
+}
+
+/// How the numbers around a gear make its ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Combine {
+    #[default]
+    Product,
+    Sum,
+}
+
+/// Which cells around a cell are adjacent to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    /// Only the cells above, below, left and right.
+    Four,
+    /// The diagonals too.
+    #[default]
+    Eight,
+}
+
+/// The rules that decide which numbers are part numbers and which
+/// characters are gears. The default rules are those of the puzzle:
+/// any character but `.` is a symbol, and a `*` next to exactly two
+/// numbers is a gear whose ratio is their product.
+///
+/// ```
+/// use aoc2023_day_03::{part_two, Combine, Graph, Rules};
+///
+/// let graph: Graph = "12.\n.#.\n.34\n".parse().unwrap();
+/// let rules = Rules::default().with_gears("#").with_gear_numbers(1..=3).with_combine(Combine::Sum);
+/// assert_eq!(12 + 34, part_two(&graph, &rules));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    symbols: Symbols,
+    gears: String,
+    gear_numbers: RangeInclusive<usize>,
+    combine: Combine,
+    neighborhood: Neighborhood,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            symbols: Symbols::AllExcept(".".to_string()),
+            gears: "*".to_string(),
+            gear_numbers: 2..=2,
+            combine: Combine::Product,
+            neighborhood: Neighborhood::Eight,
+        }
+    }
+}
+
+impl Rules {
+    pub fn with_symbols(mut self, symbols: Symbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the characters that can be gears.
+    pub fn with_gears(mut self, gears: &str) -> Self {
+        self.gears = gears.to_string();
+        self
+    }
+
+    /// Sets how many numbers must be adjacent to a gear, such as `2..=2`.
+    pub fn with_gear_numbers(mut self, range: RangeInclusive<usize>) -> Self {
+        self.gear_numbers = range;
+        self
+    }
+
+    pub fn with_combine(mut self, combine: Combine) -> Self {
+        self.combine = combine;
+        self
+    }
+
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    pub fn is_symbol(&self, c: char) -> bool {
+        !c.is_numeric() && match &self.symbols {
+            Symbols::AllExcept(chars) => !chars.contains(c),
+            Symbols::Only(chars) => chars.contains(c),
+        }
+    }
+
+    pub fn is_gear(&self, c: char) -> bool {
+        self.gears.contains(c)
+    }
+
+    /// The ratio of a gear with the given adjacent numbers, or `None` if
+    /// there are too few or too many of them for it to be a gear.
+    pub fn ratio(&self, numbers: &[u64]) -> Option<u64> {
+        if !self.gear_numbers.contains(&numbers.len()) {
+            return None;
+        }
+        match self.combine {
+            Combine::Product => Some(numbers.iter().product()),
+            Combine::Sum => Some(numbers.iter().sum()),
+        }
+    }
+
+    /// The cells adjacent to the point.
+    pub fn neighbors(&self, point: Point) -> impl Iterator<Item = Point> {
+        let directions: &'static [Direction] = match self.neighborhood {
+            Neighborhood::Four => &Direction::ALL_4,
+            Neighborhood::Eight => &Direction::ALL_8,
+        };
+        directions.iter().map(move |&direction| point.step(direction))
+    }
+}
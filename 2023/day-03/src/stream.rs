@@ -0,0 +1,281 @@
+use std::fmt;
+use std::io::{self, BufRead};
+use aoc_utils::PuzzleError;
+use aoc_utils::grid::ParseGridError;
+
+use crate::{push_digit, too_large, Neighborhood, Rules};
+
+/// Solves both parts one line at a time, for schematics too large to
+/// hold in memory as a [`Graph`](crate::Graph).
+///
+/// Only three rows are kept at once: a row is solved when the row below
+/// it arrives, and then only serves as the row above the next one. Lines
+/// are either pushed one by one, or read from a file with
+/// [`Stream::solve_reader`].
+///
+/// ```
+/// use aoc2023_day_03::Stream;
+///
+/// let mut stream = Stream::new();
+/// for line in ["467..114..", "...*......", "..35..633."] {
+///     stream.push(line).unwrap();
+/// }
+/// assert_eq!((467 + 35, 467 * 35), stream.finish());
+/// ```
+#[derive(Debug, Default)]
+pub struct Stream {
+    rules: Rules,
+    above: Option<Row>,
+    current: Option<Row>,
+    width: Option<usize>,
+    line: usize,
+    part_one: u64,
+    part_two: u64,
+}
+
+/// A row of the schematic, with the numbers on it.
+#[derive(Debug)]
+struct Row {
+    cells: Vec<char>,
+    numbers: Vec<Number>,
+}
+
+/// A number spanning the columns `start..end` of its row.
+#[derive(Debug)]
+struct Number {
+    start: usize,
+    end: usize,
+    value: u64,
+}
+
+impl Stream {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rules(rules: Rules) -> Self {
+        Self { rules, ..Self::default() }
+    }
+
+    /// Adds the next line of the schematic. Like when parsing a `Graph`,
+    /// lines are trimmed, blank lines are skipped, and every row must be
+    /// as wide as the first one.
+    pub fn push(&mut self, text: &str) -> Result<(), PuzzleError> {
+        self.line += 1;
+        let line = text.trim();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let row = Row::new(line.chars().collect()).map_err(|col| too_large(self.line, text, col))?;
+        let width = *self.width.get_or_insert(row.cells.len());
+        if row.cells.len() != width {
+            return Err(ParseGridError::Ragged { line: self.line, expected: width, found: row.cells.len() }.into());
+        }
+
+        if let Some(current) = self.current.take() {
+            self.solve_row(&current, Some(&row));
+            self.above = Some(current);
+        }
+        self.current = Some(row);
+        Ok(())
+    }
+
+    /// Solves the last row and returns the answers to both parts.
+    pub fn finish(mut self) -> (u64, u64) {
+        if let Some(current) = self.current.take() {
+            self.solve_row(&current, None);
+        }
+        (self.part_one, self.part_two)
+    }
+
+    /// Pushes every line of `reader` and returns the answers to both
+    /// parts, without ever holding more than a line of the input.
+    pub fn solve_reader(mut self, reader: impl BufRead) -> Result<(u64, u64), ReadError> {
+        for line in reader.lines() {
+            self.push(&line?)?;
+        }
+        Ok(self.finish())
+    }
+
+    fn solve_row(&mut self, row: &Row, below: Option<&Row>) {
+        let neighborhood = self.rules.neighborhood();
+        // Each row, and whether it is the one being solved.
+        let rows: Vec<(&Row, bool)> = [(self.above.as_ref(), false), (Some(row), true), (below, false)]
+            .into_iter()
+            .filter_map(|(r, same)| r.map(|r| (r, same)))
+            .collect();
+
+        for number in &row.numbers {
+            let cols = number.start.saturating_sub(1)..(number.end + 1).min(row.cells.len());
+            let has_symbol = rows.iter().any(|&(r, same)| {
+                cols.clone().any(|col| self.rules.is_symbol(r.cells[col]) && number.touches(col, same, neighborhood))
+            });
+            if has_symbol {
+                self.part_one += number.value;
+            }
+        }
+
+        for (col, _) in row.cells.iter().enumerate().filter(|(_, &c)| self.rules.is_gear(c)) {
+            let adjacent: Vec<u64> = rows
+                .iter()
+                .flat_map(|&(r, same)| r.numbers.iter().filter(move |number| number.touches(col, same, neighborhood)))
+                .map(|number| number.value)
+                .collect();
+            if let Some(ratio) = self.rules.ratio(&adjacent) {
+                self.part_two += ratio;
+            }
+        }
+    }
+}
+
+/// Why [`Stream::solve_reader`] could not solve a schematic.
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading failed, or a line was not UTF-8.
+    Io(io::Error),
+    Malformed(PuzzleError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Malformed(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<PuzzleError> for ReadError {
+    fn from(err: PuzzleError) -> Self {
+        ReadError::Malformed(err)
+    }
+}
+
+impl Number {
+    /// Whether the number is adjacent to the cell at `col`, either in
+    /// its own row or in the row above or below it.
+    fn touches(&self, col: usize, same_row: bool, neighborhood: Neighborhood) -> bool {
+        match (neighborhood, same_row) {
+            (Neighborhood::Eight, _) => self.start <= col + 1 && col <= self.end,
+            (Neighborhood::Four, true) => self.end == col || self.start == col + 1,
+            (Neighborhood::Four, false) => self.start <= col && col < self.end,
+        }
+    }
+}
+
+impl Row {
+    /// Finds the numbers on the row, failing with the column of the
+    /// first one too large for a `u64`.
+    fn new(cells: Vec<char>) -> Result<Self, usize> {
+        let mut numbers = Vec::new();
+        let mut col = 0;
+        while col < cells.len() {
+            if cells[col].is_ascii_digit() {
+                let mut number = Number { start: col, end: col, value: 0 };
+                while let Some(digit) = cells.get(col).and_then(|c| c.to_digit(10)) {
+                    number.value = push_digit(number.value, digit).ok_or(number.start)?;
+                    col += 1;
+                }
+                number.end = col;
+                numbers.push(number);
+            } else {
+                col += 1;
+            }
+        }
+        Ok(Self { cells, numbers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::{PuzzleError, Rng};
+    use super::{ReadError, Stream};
+    use crate::{generate, part_one, part_two, Combine, Graph, Neighborhood, Rules, Symbols};
+
+    fn stream(input: &str, rules: &Rules) -> Result<(u64, u64), PuzzleError> {
+        let mut stream = Stream::with_rules(rules.clone());
+        for line in input.lines() {
+            stream.push(line)?;
+        }
+        Ok(stream.finish())
+    }
+
+    fn in_memory(input: &str, rules: &Rules) -> Result<(u64, u64), PuzzleError> {
+        let graph = input.parse::<Graph>()?;
+        Ok((part_one(&graph, rules), part_two(&graph, rules)))
+    }
+
+    #[test]
+    fn test_generated() {
+        let rules = Rules::default();
+        for seed in 0..5 {
+            let input = generate(seed, 1);
+            assert_eq!(in_memory(&input, &rules), stream(&input, &rules));
+        }
+    }
+
+    #[test]
+    fn test_random() {
+        // Small, dense schematics, so that numbers and gears often touch
+        // the edges and each other.
+        let mut rng = Rng::new(3);
+        for _ in 0..1000 {
+            let (rows, cols) = (rng.range(1..8), rng.range(1..8));
+            let mut input = String::new();
+            for _ in 0..rows {
+                for _ in 0..cols {
+                    input.push(*rng.choose(&['.', '.', '.', '1', '2', '7', '*', '*', '#']));
+                }
+                input.push('\n');
+            }
+
+            let mut rules = Rules::default();
+            if rng.chance(0.5) {
+                rules = rules.with_neighborhood(Neighborhood::Four);
+            }
+            if rng.chance(0.5) {
+                rules = rules.with_symbols(Symbols::Only("#".to_string())).with_gears("*#");
+            }
+            if rng.chance(0.5) {
+                let min = rng.range(0..3) as usize;
+                rules = rules.with_gear_numbers(min..=min + rng.range(0..3) as usize).with_combine(Combine::Sum);
+            }
+            assert_eq!(in_memory(&input, &rules), stream(&input, &rules), "{}{:?}", input, rules);
+        }
+    }
+
+    #[test]
+    fn test_malformed() {
+        let input = "467..\n\n..*..\n..35\n";
+        let rules = Rules::default();
+        assert!(stream(input, &rules).is_err());
+        assert_eq!(in_memory(input, &rules), stream(input, &rules));
+
+        let digits = "1".repeat(25);
+        let input = format!("{}\n\n  .{}.\n", ".".repeat(27), digits);
+        let expected = Err(PuzzleError::new(3, 4, "a number that fits in u64"));
+        assert_eq!(expected, stream(&input, &rules));
+        assert_eq!(expected, in_memory(&input, &rules));
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        let input = generate(7, 1);
+        let solved = Stream::new().solve_reader(input.as_bytes()).expect("failed to solve");
+        assert_eq!(in_memory(&input, &Rules::default()), Ok(solved));
+
+        let malformed = Stream::new().solve_reader("467..\n..*..\n..35\n".as_bytes());
+        assert!(matches!(malformed, Err(ReadError::Malformed(err)) if err.line == 3));
+        let binary = Stream::new().solve_reader(&b"467..\n\xff.*..\n"[..]);
+        assert!(matches!(binary, Err(ReadError::Io(_))));
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt::Write;
+use aoc_utils::Rng;
+
+/// A card with at least this many copies wins nothing, which ends every
+/// chain of wins through it.
+const COPY_LIMIT: u64 = 10_000;
+
+/// Generates a valid input with `1000 * scale` cards of ten winning
+/// numbers and twenty-five numbers each. The same seed and scale always
+/// give the same input.
+///
+/// A card wins up to ten cards, and the chance that it wins any rises
+/// with the scale, from one in four to nine in ten. Above one in five
+/// or so, every card wins more than one copy on average and the copies
+/// multiply along the chains of winning cards, as in the official
+/// input. Left alone they would grow exponentially, so the number of
+/// copies of each card is kept below about twice `COPY_LIMIT`.
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let cards = 1000 * scale;
+    let chance = (0.1 + 0.15 * scale as f64).min(0.9);
+    let mut copies = vec![1; cards];
+    let mut result = String::new();
+    for id in 1..=cards {
+        let wins = match copies[id - 1] < COPY_LIMIT && rng.chance(chance) {
+            // Cards never win copies past the end of the table.
+            true => (rng.range(1..11) as usize).min(cards - id),
+            false => 0,
+        };
+        for i in id..id + wins {
+            copies[i] += copies[id - 1];
+        }
+
+        let numbers = rng.sample(1..100, 35 - wins);
+        let winning = &numbers[..10];
+        let mut have: Vec<u64> = winning[..wins].iter().chain(&numbers[10..]).copied().collect();
+        rng.shuffle(&mut have);
+
+        let format = |numbers: &[u64]| numbers.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>().join(" ");
+        writeln!(result, "Card {:4}: {} | {}", id, format(winning), format(&have)).unwrap();
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, COPY_LIMIT};
+    use crate::{parse_cards, part_two};
+
+    /// The number of copies of each card, counted front to back.
+    fn copies(cards: &[usize]) -> Vec<u64> {
+        let mut copies = vec![1; cards.len()];
+        for (i, &wins) in cards.iter().enumerate() {
+            for j in i + 1..=i + wins {
+                copies[j] += copies[i];
+            }
+        }
+        copies
+    }
+
+    #[test]
+    fn test_generate() {
+        for scale in [1, 2] {
+            let input = generate(7, scale);
+            for line in input.lines() {
+                let (winning, have) = line.split_once(':').and_then(|(_, numbers)| numbers.split_once('|')).expect("expected a card");
+                assert_eq!((10, 25), (winning.split_whitespace().count(), have.split_whitespace().count()));
+            }
+
+            let cards = parse_cards(&input).expect("failed to parse cards");
+            assert_eq!(1000 * scale, cards.len());
+            assert!(cards.iter().enumerate().all(|(i, &wins)| i + wins < cards.len()));
+
+            // Some cards win the full ten, and wins chain: a winning card
+            // wins another winning card.
+            assert_eq!(Some(&10), cards.iter().max());
+            assert!(cards.iter().enumerate().any(|(i, &wins)| wins > 0 && cards[i + 1..=i + wins].iter().any(|&w| w > 0)));
+        }
+    }
+
+    #[test]
+    fn test_generate_chains() {
+        // The copies multiply faster at a larger scale, but stay bounded.
+        let mean = |scale: usize| {
+            let cards = parse_cards(&generate(7, scale)).expect("failed to parse cards");
+            let copies = copies(&cards);
+            assert!(copies.iter().all(|&n| n < 2 * COPY_LIMIT));
+            assert_eq!(copies.iter().sum::<u64>() as usize, part_two(&cards));
+            copies.iter().sum::<u64>() / cards.len() as u64
+        };
+        let (one, two, three) = (mean(1), mean(2), mean(3));
+        assert!(one > 2 && two > 10 * one && three > two && three > COPY_LIMIT / 10, "{} {} {}", one, two, three);
+    }
+}
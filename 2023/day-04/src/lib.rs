@@ -0,0 +1,69 @@
+use std::fs;
+use std::process;
+use aoc_utils::PuzzleError;
+use aoc_utils::parse::{Input, PResult};
+use aoc_utils::parse::{lines, many0, map, pair, parse_all, preceded, space1, tag, terminated, uint};
+
+mod generate;
+
+pub use generate::generate;
+
+pub fn run_all(file_path: &str) {
+    let input = fs::read_to_string(file_path).expect("failed to read input file");
+    let (one, two) = match solve(&input) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.with_file(file_path).render(&input));
+            process::exit(1);
+        },
+    };
+    println!("Day 04");
+    println!("    Part One: {}", one);
+    println!("    Part Two: {}", two);
+}
+
+/// Solves both parts for the given input.
+pub fn solve(input: &str) -> Result<(String, String), PuzzleError> {
+    let cards = parse_cards(input)?;
+    Ok((part_one(&cards).to_string(), part_two(&cards).to_string()))
+}
+
+pub fn part_one(cards: &[usize]) -> u64 {
+    cards 
+       .iter()
+       .filter(|x| **x > 0)
+       .map(|x| 1 << (x - 1))
+       .sum()
+}
+
+pub fn part_two(cards: &Vec<usize>) -> usize {
+    fn recursively_add_duplicates(dups: &mut Vec<usize>, cards: &Vec<usize>, cur: usize) {
+        for i in cur + 1..=cur + cards[cur] {
+            recursively_add_duplicates(dups, cards, i);
+            dups.push(i);
+        }
+    }
+
+    let mut dups = Vec::new();
+    for i in 0..cards.len() {
+        recursively_add_duplicates(&mut dups, cards, i);
+    }
+
+    cards.len() + dups.len()
+}
+
+/// Parses the cards, returning the number of winning numbers on each.
+pub fn parse_cards(input: &str) -> Result<Vec<usize>, PuzzleError> {
+    parse_all(lines(card), input)
+}
+
+/// A single card, such as `Card 1: 41 48 83 | 83 86  6 31`.
+fn card(input: Input<'_>) -> PResult<'_, usize> {
+    let id = preceded(pair(tag("Card"), space1), uint::<u64>);
+    let numbers = || many0(preceded(space1, uint::<u64>));
+    let card = preceded(
+        terminated(id, tag(":")),
+        pair(terminated(numbers(), preceded(space1, tag("|"))), numbers()),
+    );
+    map(card, |(winning, numbers)| numbers.iter().filter(|n| winning.contains(n)).count())(input)
+}
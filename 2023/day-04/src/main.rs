@@ -1,10 +1,10 @@
 fn main() {
-    day_04::run_all();
+    aoc2023_day_04::run_all("input/2023/4.txt");
 }
 
 #[cfg(test)]
 mod tests {
-    use day_04::{parse_cards, part_one, part_two};
+    use aoc2023_day_04::{parse_cards, part_one, part_two};
 
     const INPUT: &str = concat!(
         "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n",
@@ -17,13 +17,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let cards = parse_cards(INPUT);
+        let cards = parse_cards(INPUT).expect("failed to parse cards");
         assert_eq!(13, part_one(&cards));
     }
 
     #[test]
     fn test_part_two() {
-        let cards = parse_cards(INPUT);
+        let cards = parse_cards(INPUT).expect("failed to parse cards");
         assert_eq!(30, part_two(&cards));
     }
 }
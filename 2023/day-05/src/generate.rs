@@ -0,0 +1,71 @@
+use std::fmt::Write;
+use aoc_utils::Rng;
+
+const TABLES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Values are below 2^32, as in the official inputs.
+const LIMIT: u64 = 1 << 32;
+
+/// Generates a valid almanac with `10 * scale` seed ranges and
+/// `30 * scale` ranges in each of the seven tables. The same seed and
+/// scale always give the same input.
+pub fn generate(seed: u64, scale: usize) -> String {
+    let mut rng = Rng::new(seed);
+    let mut result = String::from("seeds:");
+    for _ in 0..10 * scale {
+        let length = rng.range(1..1 << 28);
+        write!(result, " {} {}", rng.below(LIMIT - length), length).unwrap();
+    }
+    result.push('\n');
+
+    for name in TABLES {
+        write!(result, "\n{} map:\n", name).unwrap();
+
+        // Pairs of sorted cut points give source ranges that never overlap.
+        let mut cuts: Vec<u64> = (0..60 * scale).map(|_| rng.below(LIMIT)).collect();
+        cuts.sort();
+        cuts.dedup();
+        let mut ranges: Vec<(u64, u64)> = cuts.chunks_exact(2).map(|pair| (pair[0], pair[1] - pair[0])).collect();
+        rng.shuffle(&mut ranges);
+
+        for (src, length) in ranges {
+            writeln!(result, "{} {} {}", rng.below(LIMIT - length), src, length).unwrap();
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, LIMIT};
+    use crate::{parse_seeds_part_two, parse_transition_tables};
+
+    #[test]
+    fn test_generate() {
+        for scale in [1, 4] {
+            let input = generate(7, scale);
+            let seeds = parse_seeds_part_two(input.lines().next().unwrap_or("")).expect("failed to parse seeds");
+            assert_eq!(10 * scale, seeds.len());
+            assert!(seeds.iter().all(|range| !range.is_empty() && range.end <= LIMIT));
+
+            // Cut points can coincide, so a table may lose a range or two.
+            let tables = parse_transition_tables(&input).expect("failed to parse transition tables");
+            assert_eq!(7, tables.len());
+            for table in &tables {
+                assert!((28 * scale..=30 * scale).contains(&table.matches.len()));
+                let mut sources: Vec<_> = table.matches.iter().map(|pair| pair.src.clone()).collect();
+                sources.sort_by_key(|range| range.start);
+                assert!(sources.windows(2).all(|pair| pair[0].end <= pair[1].start));
+                assert!(table.matches.iter().all(|pair| pair.src.end <= LIMIT && pair.dst.end <= LIMIT));
+            }
+        }
+    }
+}
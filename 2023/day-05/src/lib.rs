@@ -0,0 +1,274 @@
+use std::fs;
+use std::process;
+use std::ops::Range;
+use std::str::FromStr;
+use aoc_utils::{IntervalSet, PuzzleError};
+use aoc_utils::parse::{Input, PResult};
+use aoc_utils::parse::{blank_lines, line, lines, many1, map, opt, pair, parse_all, preceded, row, section, sections, space1, tag, terminated, uint};
+
+mod generate;
+
+pub use generate::generate;
+
+pub fn run_all(file_path: &str) {
+    let contents = fs::read_to_string(file_path).expect("failed to read input file");
+    let (one, two) = match solve(&contents) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.with_file(file_path).render(&contents));
+            process::exit(1);
+        },
+    };
+    println!("Day 05");
+    println!("    Part One: {}", one);
+    println!("    Part Two: {}", two);
+}
+
+/// Solves both parts for the given input.
+pub fn solve(contents: &str) -> Result<(String, String), PuzzleError> {
+    // Both parts parse the seeds themselves, but check them here so
+    // that a malformed first line is reported instead of panicking.
+    let first_line = contents.lines().next().unwrap_or("");
+    parse_seeds_part_one(first_line)?;
+    parse_seeds_part_two(first_line)?;
+
+    let tables = parse_transition_tables(contents)?;
+    Ok((part_one(contents, &tables).to_string(), part_two(contents, &tables).to_string()))
+}
+
+pub fn part_one(input: &str, tables: &[Table]) -> u64 {
+    let seeds = parse_seeds_part_one(
+        input.lines().next().expect("expected first line"),
+    ).expect("failed to parse seeds");
+
+    seeds
+        .iter()
+        .map(|seed| tables.iter().fold(*seed, |acc, table| table.forward(acc)))
+        .min()
+        .expect("expected at least one seed")
+}
+
+pub fn part_two(input: &str, tables: &[Table]) -> u64 {
+    let seed_ranges = parse_seeds_part_two(
+        input.lines().next().expect("expected first line"),
+    ).expect("failed to parse seeds");
+
+    let seeds: IntervalSet<u64> = seed_ranges.into_iter().collect();
+
+    tables
+        .iter()
+        .fold(seeds, |acc, table| table.forward_ranges(&acc))
+        .min()
+        .expect("expected at least one seed")
+}
+
+/// A range pair is a mapping from a source range
+/// to a destination range.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RangePair {
+    src: Range<u64>,
+    dst: Range<u64>,
+}
+
+impl RangePair {
+    fn contains(&self, num: u64) -> bool {
+        self.src.contains(&num)
+    }
+
+    fn translate(&self, num: u64) -> u64 {
+        self.dst.start + num - self.src.start
+    }
+}
+
+impl FromStr for RangePair {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(range_pair, s)
+    }
+}
+
+/// A line of the form `dst src len`.
+fn range_pair(input: Input<'_>) -> PResult<'_, RangePair> {
+    let ([dst, src, len], rest) = row::<u64, 3>(input)?;
+    match (range(src, len), range(dst, len)) {
+        (Some(src), Some(dst)) => Ok((RangePair { src, dst }, rest)),
+        _ => input.error("ranges that end below 2^64"),
+    }
+}
+
+/// The range `start..start + length`, if it fits in a `u64`.
+fn range(start: u64, length: u64) -> Option<Range<u64>> {
+    Some(start..start.checked_add(length)?)
+}
+
+/// A table is a list of transitions from source to
+/// destination ranges.
+///
+/// For example:
+///     `[0..10] => [50..60]`
+///     `[20..25] => [80..85]`
+///     `_ => x`
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Table {
+    matches: Vec<RangePair>,
+}
+
+impl Table {
+    /// Maps the number to the corresponding range from
+    /// the list of matches in the transition table, or
+    /// returns the original number.
+    fn forward(&self, x: u64) -> u64 {
+        self.matches
+            .iter()
+            .find(|&range_pair| range_pair.contains(x))
+            .map(|range_pair| range_pair.translate(x))
+            .unwrap_or(x)
+        
+        // self.matches.iter().fold(x, |acc, range_pair| range_pair.translate(acc))
+    }
+
+    /// Maps a whole set of numbers at once by splitting it along the
+    /// source ranges, so that every piece is translated as a unit.
+    fn forward_ranges(&self, set: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut unmapped = set.clone();
+        let mut result = Vec::new();
+        for range_pair in &self.matches {
+            // As in `forward`, only the first matching range applies.
+            let src = IntervalSet::coalesce([range_pair.src.clone()]);
+            for range in unmapped.intersection(&src) {
+                result.push(range_pair.translate(range.start)..range_pair.translate(range.end - 1) + 1);
+            }
+            unmapped = unmapped.difference(&src);
+        }
+        result.extend(unmapped);
+        IntervalSet::coalesce(result)
+    }
+}
+
+impl FromStr for Table {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_all(table, s)
+    }
+}
+
+/// A labelled table, such as `seed-to-soil map:` followed by its
+/// range pairs. The label is not needed, as the tables are in order.
+fn table(input: Input<'_>) -> PResult<'_, Table> {
+    map(section(lines(range_pair)), |(_, matches)| Table { matches })(input)
+}
+
+/// The numbers after `seeds:`, at least one.
+fn seeds<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    preceded(tag("seeds:"), many1(preceded(space1, p)))
+}
+
+/// Parses a list of numbers corresponding to a list of seeds.
+pub fn parse_seeds_part_one(first_line: &str) -> Result<Vec<u64>, PuzzleError> {
+    parse_all(seeds(uint::<u64>), first_line)
+}
+
+/// Parses a list of pairs of numbers corresponding to `pairs` of seeds,
+/// where the first number is the starting seed, and the second is the
+/// length of the range: `start..start + length`.
+pub fn parse_seeds_part_two(first_line: &str) -> Result<Vec<Range<u64>>, PuzzleError> {
+    let seed_range = |input| {
+        let ((start, length), rest) = pair(uint::<u64>, preceded(space1, uint::<u64>))(input)?;
+        match range(start, length) {
+            Some(range) => Ok((range, rest)),
+            None => input.error("a range that ends below 2^64"),
+        }
+    };
+    parse_all(seeds(seed_range), first_line)
+}
+
+/// Parses the tables that follow the seeds. The `seeds:` line
+/// itself is skipped if present.
+pub fn parse_transition_tables(input: &str) -> Result<Vec<Table>, PuzzleError> {
+    let seeds_line = terminated(line(seeds(uint::<u64>)), blank_lines);
+    parse_all(preceded(opt(seeds_line), sections(table)), input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part_one, part_two, parse_transition_tables, solve, Table};
+
+    const INPUT: &str = concat!(
+        "seeds: 79 14 55 13\n",
+        "\n",
+        "seed-to-soil map:\n",
+        "50 98 2\n",
+        "52 50 48\n",
+        "\n",
+        "soil-to-fertilizer map:\n",
+        "0 15 37\n",
+        "37 52 2\n",
+        "39 0 15\n",
+        "\n",
+        "fertilizer-to-water map:\n",
+        "49 53 8\n",
+        "0 11 42\n",
+        "42 0 7\n",
+        "57 7 4\n",
+        "\n",
+        "water-to-light map:\n",
+        "88 18 7\n",
+        "18 25 70\n",
+        "\n",
+        "light-to-temperature map:\n",
+        "45 77 23\n",
+        "81 45 19\n",
+        "68 64 13\n",
+        "\n",
+        "temperature-to-humidity map:\n",
+        "0 69 1\n",
+        "1 0 69\n",
+        "\n",
+        "humidity-to-location map:\n",
+        "60 56 37\n",
+        "56 93 4\n",
+    );
+
+    #[test]
+    fn test_part_one() {
+        let tables = parse_transition_tables(INPUT).expect("failed to parse transition tables");
+        assert_eq!(35, part_one(INPUT, &tables));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let tables = parse_transition_tables(INPUT).expect("failed to parse transition tables");
+        assert_eq!(46, part_two(INPUT, &tables));
+    }
+
+    #[test]
+    fn test_parse_tables() {
+        let input = concat!(
+            "seed-to-soil map:\n",
+            "50 98 2\n",
+            "52 50 48\n",
+        );
+
+        let table = input.parse::<Table>().expect("failed to parse table");
+        assert_eq!(2, table.matches.len());
+        assert_eq!(50, table.matches[0].dst.start);
+        assert_eq!(98, table.matches[0].src.start);
+
+        let err = parse_transition_tables("seeds: 1 2\n\nseed-to-soil map:\n50 98\n").err().expect("expected a parse error");
+        assert_eq!((4, 6), (err.line, err.column));
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = |input: &str| solve(input).err().map(|err| err.to_string());
+        assert_eq!(Some("1:7: expected whitespace".to_string()), error("seeds:\n\nseed-to-soil map:\n1 2 3\n"));
+        let overflow = "seeds: 1 2\n\nseed-to-soil map:\n1 18446744073709551615 3\n";
+        assert_eq!(Some("4:1: expected ranges that end below 2^64".to_string()), error(overflow));
+        let overflow = "seeds: 18446744073709551615 3\n\nseed-to-soil map:\n1 2 3\n";
+        assert_eq!(Some("1:8: expected a range that ends below 2^64".to_string()), error(overflow));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc2023_day_05::run_all("input/2023/5.txt");
+}
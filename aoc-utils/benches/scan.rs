@@ -0,0 +1,64 @@
+use std::fmt::Write;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use aoc_utils::parse::{parse_all, section, sections, table};
+use aoc_utils::scan;
+
+/// An almanac in the format of 2023 day 5: sections of `dst src len` rows.
+fn almanac(sections: usize, rows: usize) -> String {
+    // A fixed LCG keeps the input identical between runs.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        state >> 32
+    };
+
+    let mut result = String::new();
+    for i in 0..sections {
+        writeln!(result, "section-{} map:", i).unwrap();
+        for _ in 0..rows {
+            writeln!(result, "{} {} {}", next(), next(), next() % 1000).unwrap();
+        }
+        result.push('\n');
+    }
+    result
+}
+
+fn bench_integers(c: &mut Criterion) {
+    let input = almanac(20, 1000);
+    let mut group = c.benchmark_group("integers");
+
+    group.bench_function("split_whitespace", |b| b.iter(|| {
+        black_box(&input)
+            .lines()
+            .flat_map(str::split_whitespace)
+            .filter_map(|x| x.parse::<u64>().ok())
+            .sum::<u64>()
+    }));
+    group.bench_function("combinators", |b| b.iter(|| {
+        parse_all(sections(section(table::<u64, 3>)), black_box(&input))
+            .unwrap()
+            .iter()
+            .flat_map(|(_, rows)| rows.iter().flatten())
+            .sum::<u64>()
+    }));
+    group.bench_function("scan", |b| b.iter(|| {
+        scan::integers::<u64>(black_box(&input)).sum::<u64>()
+    }));
+    group.finish();
+}
+
+fn bench_sections(c: &mut Criterion) {
+    let input = almanac(2000, 10);
+    let mut group = c.benchmark_group("sections");
+
+    group.bench_function("split", |b| b.iter(|| {
+        black_box(&input).split("\n\n").filter(|s| !s.trim().is_empty()).count()
+    }));
+    group.bench_function("scan", |b| b.iter(|| {
+        scan::sections(black_box(&input)).count()
+    }));
+    group.finish();
+}
+
+criterion_group!(benches, bench_integers, bench_sections);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::grid::ParseGridError;
+
+/// A malformed puzzle input, pointing at the offending character.
+///
+/// Solutions return this instead of panicking, so that the runner can
+/// show where the input went wrong with [`PuzzleError::render`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleError {
+    /// The input file, if known. Solutions only see the contents, so
+    /// this is filled in by whoever read the file.
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// Description of what was expected at this position.
+    pub expected: String,
+}
+
+impl PuzzleError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self { file: None, line, column, expected: expected.into() }
+    }
+
+    /// Sets the file the input was read from.
+    pub fn with_file(self, file: impl Into<PathBuf>) -> Self {
+        Self { file: Some(file.into()), ..self }
+    }
+
+    /// Formats the error like a compiler diagnostic, with the offending
+    /// line of `src` and a caret under the column:
+    ///
+    /// ```text
+    /// error: expected an integer
+    ///  --> input/2023/5.txt:4:6
+    ///   |
+    /// 4 | 50 98
+    ///   |      ^
+    /// ```
+    pub fn render(&self, src: &str) -> String {
+        let text = src.lines().nth(self.line.saturating_sub(1)).unwrap_or("");
+        // Keep tabs so that the caret lines up with the text above it.
+        let indent: String = text
+            .chars()
+            .take(self.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        let mut result = format!("error: expected {}\n", self.expected);
+        result += &format!("{}--> {}\n", gutter, self.location());
+        result += &format!("{} |\n", gutter);
+        result += &format!("{} | {}\n", self.line, text);
+        result += &format!("{} | {}^", gutter, indent);
+        result
+    }
+
+    fn location(&self) -> String {
+        match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("{}:{}", self.line, self.column),
+        }
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: expected {}", self.location(), self.expected)
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+impl From<ParseGridError> for PuzzleError {
+    fn from(err: ParseGridError) -> Self {
+        match err {
+            ParseGridError::Ragged { line, expected, found } => {
+                let column = expected.min(found) + 1;
+                Self::new(line, column, format!("a line of {} cells, found {}", expected, found))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PuzzleError;
+
+    #[test]
+    fn test_render() {
+        let err = PuzzleError::new(2, 4, "an integer").with_file("input/1.txt");
+        assert_eq!("input/1.txt:2:4: expected an integer", err.to_string());
+
+        let expected = concat!(
+            "error: expected an integer\n",
+            " --> input/1.txt:2:4\n",
+            "  |\n",
+            "2 | ab\tx\n",
+            "  |   \t^",
+        );
+        assert_eq!(expected, err.render("first\nab\tx\n"));
+    }
+}
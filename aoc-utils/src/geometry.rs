@@ -0,0 +1,349 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on a 2D grid.
+///
+/// Like [`Grid`](crate::Grid), points are `(row, col)` with rows growing
+/// downwards, so `North` decreases the row. Coordinates are signed, so
+/// that stepping off the top or left edge is representable.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// The difference between two points.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// Converts to grid indices, or `None` if either coordinate is negative.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.row).ok()?, usize::try_from(self.col).ok()?))
+    }
+
+    /// The neighbouring point in the given direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.vector()
+    }
+
+    /// The four orthogonal neighbours, in the order of [`Direction::ALL_4`].
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL_4.into_iter().map(move |d| self.step(d))
+    }
+
+    /// All eight neighbours, in the order of [`Direction::ALL_8`].
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL_8.into_iter().map(move |d| self.step(d))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        Metric::Manhattan.distance(self, other)
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        Metric::Chebyshev.distance(self, other)
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { row: 0, col: 0 };
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// Length in steps along the axes.
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+
+    /// Length in king moves, where a diagonal step counts as one.
+    pub fn chebyshev(self) -> usize {
+        self.row.unsigned_abs().max(self.col.unsigned_abs())
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row: row as isize, col: col as isize }
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<(isize, isize)> for Vector {
+    fn from((row, col): (isize, isize)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.row + v.row, self.col + v.col)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        self + -v
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: isize) -> Vector {
+        Vector::new(self.row * n, self.col * n)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// One of the eight compass directions, with north pointing up the grid.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The orthogonal directions, clockwise from north.
+    pub const ALL_4: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// All directions, clockwise from north.
+    pub const ALL_8: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest,
+    ];
+
+    /// The offset of a single step in this direction.
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(-1, 0),
+            Direction::NorthEast => Vector::new(-1, 1),
+            Direction::East => Vector::new(0, 1),
+            Direction::SouthEast => Vector::new(1, 1),
+            Direction::South => Vector::new(1, 0),
+            Direction::SouthWest => Vector::new(1, -1),
+            Direction::West => Vector::new(0, -1),
+            Direction::NorthWest => Vector::new(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn; negative values
+    /// rotate counter-clockwise.
+    pub fn rotate(self, eighths: isize) -> Self {
+        let i = Self::ALL_8.iter().position(|&d| d == self).unwrap() as isize;
+        Self::ALL_8[(i + eighths).rem_euclid(8) as usize]
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let v = self.vector();
+        v.row != 0 && v.col != 0
+    }
+}
+
+/// A way of measuring the distance between two points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// Steps along the axes, as for 4-way movement.
+    Manhattan,
+    /// King moves, as for 8-way movement.
+    Chebyshev,
+}
+
+impl Metric {
+    pub fn distance(self, a: Point, b: Point) -> usize {
+        match self {
+            Metric::Manhattan => (a - b).manhattan(),
+            Metric::Chebyshev => (a - b).chebyshev(),
+        }
+    }
+}
+
+/// The smallest rectangle containing a set of points. Both corners are
+/// inclusive, so a single point has a width and height of one.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// A box containing just the given point.
+    pub fn new(point: Point) -> Self {
+        Self { min: point, max: point }
+    }
+
+    /// The box around all points, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut result = Self::new(points.next()?);
+        for point in points {
+            result.include(point);
+        }
+        Some(result)
+    }
+
+    /// Grows the box so that it contains `point`.
+    pub fn include(&mut self, point: Point) {
+        self.min = Point::new(self.min.row.min(point.row), self.min.col.min(point.col));
+        self.max = Point::new(self.max.row.max(point.row), self.max.col.max(point.col));
+    }
+
+    /// The box grown by `n` cells on every side.
+    pub fn expand(self, n: isize) -> Self {
+        let v = Vector::new(n, n);
+        Self { min: self.min - v, max: self.max + v }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.row..=self.max.row).contains(&point.row)
+            && (self.min.col..=self.max.col).contains(&point.col)
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.row - self.min.row) as usize + 1
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.col - self.min.col) as usize + 1
+    }
+
+    /// Iterates over every point in the box in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        (min.row..=max.row).flat_map(move |row| (min.col..=max.col).map(move |col| Point::new(row, col)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundingBox, Direction, Point, Vector};
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(2, 3);
+        assert_eq!(Point::new(1, 3), p.step(Direction::North));
+        assert_eq!(Point::new(4, 7), p + Vector::new(1, 2) * 2);
+        assert_eq!(Vector::new(2, -1), p - Point::new(0, 4));
+        assert_eq!(3, p.manhattan(Point::new(0, 4)));
+        assert_eq!(2, p.chebyshev(Point::new(0, 4)));
+        assert_eq!(Some((2, 3)), p.to_index());
+        assert_eq!(None, Point::new(-1, 0).to_index());
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::NorthWest, Direction::SouthEast.opposite());
+        assert_eq!(Direction::NorthEast, Direction::North.rotate(1));
+        assert_eq!(Direction::NorthWest, Direction::North.rotate(-9));
+        for d in Direction::ALL_8 {
+            assert_eq!(Vector::ZERO, d.vector() + d.opposite().vector());
+            assert_eq!(d.is_diagonal(), d.vector().manhattan() == 2);
+        }
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let bb = BoundingBox::from_points([Point::new(1, 5), Point::new(3, 2)]).unwrap();
+        assert_eq!((3, 4), (bb.height(), bb.width()));
+        assert!(bb.contains(Point::new(2, 3)));
+        assert!(!bb.contains(Point::new(0, 3)));
+        assert_eq!(12, bb.points().count());
+        assert_eq!(30, bb.expand(1).points().count());
+        assert_eq!(None, BoundingBox::from_points([]));
+    }
+}
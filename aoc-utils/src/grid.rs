@@ -0,0 +1,320 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::geometry::Point;
+
+/// Offsets of the four orthogonal neighbours, as `(row, col)`.
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all eight neighbours, including the diagonals, as `(row, col)`.
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    ( 0, -1),          ( 0, 1),
+    ( 1, -1), ( 1, 0), ( 1, 1),
+];
+
+/// A rectangular grid of cells stored in row-major order.
+///
+/// Cells are addressed as `(row, col)`, with `(0, 0)` in the top-left
+/// corner. Lookups with [`Grid::get`] take signed coordinates, so that
+/// stepping off the edge yields `None` instead of wrapping around.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ParseGridError {
+    /// A line has a different length than the first line.
+    Ragged { line: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Ragged { line, expected, found } => {
+                write!(f, "line {} has {} cells, expected {}", line, found, expected)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row-major order.
+    ///
+    /// Panics if the number of cells is not `rows * cols`.
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Self {
+        assert_eq!(rows * cols, cells.len(), "expected {} cells", rows * cols);
+        Self { cells, rows, cols }
+    }
+
+    /// Parses one row per line, converting each character with `f`.
+    /// Lines are trimmed and blank lines are skipped.
+    pub fn parse_with<F>(s: &str, mut f: F) -> Result<Self, ParseGridError>
+        where F: FnMut(char) -> T
+    {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (i, line) in s.lines().map(str::trim).enumerate().filter(|(_, line)| !line.is_empty()) {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let found = cells.len() - before;
+
+            if rows == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(ParseGridError::Ragged { line: i + 1, expected: cols, found });
+            }
+            rows += 1;
+        }
+
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the cell at the given signed coordinates, or `None`
+    /// if they are outside the grid.
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        self.offset(row, col).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        self.offset(row, col).map(move |i| &mut self.cells[i])
+    }
+
+    /// Returns the cell at a point, or `None` if it is outside the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.get(point.row, point.col)
+    }
+
+    /// Returns true if the signed coordinates are inside the grid.
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        self.offset(row, col).is_some()
+    }
+
+    fn offset(&self, row: isize, col: isize) -> Option<usize> {
+        let row = usize::try_from(row).ok().filter(|&row| row < self.rows)?;
+        let col = usize::try_from(col).ok().filter(|&col| col < self.cols)?;
+        Some(row * self.cols + col)
+    }
+
+    /// Iterates over all cells as `((row, col), cell)` in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.cells.iter().enumerate().map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// Iterates over the in-bounds cells at the given offsets from `(row, col)`.
+    pub fn neighbors<'a>(&'a self, row: usize, col: usize, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let (r, c) = (row as isize + dr, col as isize + dc);
+            self.get(r, c).map(|cell| ((r as usize, c as usize), cell))
+        })
+    }
+
+    /// Iterates over the orthogonal neighbours of `(row, col)`.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors(row, col, &NEIGHBORS_4)
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `(row, col)`.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbors(row, col, &NEIGHBORS_8)
+    }
+
+    /// Returns a row as a slice.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Iterates over the cells of a column, from top to bottom.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {} out of bounds", col);
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// Iterates over the rows as slices.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero.
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// Iterates over the diagonal running down and to the right through
+    /// `(row, col)`, starting at its top-left end.
+    pub fn diagonal(&self, row: usize, col: usize) -> impl Iterator<Item = &T> {
+        let back = row.min(col) as isize;
+        let (r, c) = (row as isize - back, col as isize - back);
+        (0..).map_while(move |i| self.get(r + i, c + i))
+    }
+
+    /// Iterates over the diagonal running down and to the left through
+    /// `(row, col)`, starting at its top-right end.
+    pub fn anti_diagonal(&self, row: usize, col: usize) -> impl Iterator<Item = &T> {
+        let back = row.min(self.cols.saturating_sub(col + 1)) as isize;
+        let (r, c) = (row as isize - back, col as isize + back);
+        (0..).map_while(move |i| self.get(r + i, c - i))
+    }
+
+    /// Converts every cell with `f`, keeping the shape of the grid.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where F: FnMut(&T) -> U
+    {
+        Grid { cells: self.cells.iter().map(f).collect(), rows: self.rows, cols: self.cols }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self {
+        Self { cells: vec![value; rows * cols], rows, cols }
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.cols)
+            .flat_map(|c| (0..self.rows).map(move |r| (r, c)))
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect();
+        Self { cells, rows: self.cols, cols: self.rows }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.cols)
+            .flat_map(|c| (0..self.rows).rev().map(move |r| (r, c)))
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect();
+        Self { cells, rows: self.cols, cols: self.rows }
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let cells = (0..self.cols)
+            .rev()
+            .flat_map(|c| (0..self.rows).map(move |r| (r, c)))
+            .map(|(r, c)| self[(r, c)].clone())
+            .collect();
+        Self { cells, rows: self.cols, cols: self.rows }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| c)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.rows && col < self.cols, "({}, {}) out of bounds", row, col);
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.rows && col < self.cols, "({}, {}) out of bounds", row, col);
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.at(point).unwrap_or_else(|| panic!("{} out of bounds", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point.row, point.col).unwrap_or_else(|| panic!("{} out of bounds", point))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, ParseGridError};
+    use crate::geometry::Point;
+
+    const INPUT: &str = concat!(
+        "abc\n",
+        "def\n",
+    );
+
+    #[test]
+    fn test_parse() {
+        let grid = INPUT.parse::<Grid<char>>().expect("failed to parse grid");
+        assert_eq!((2, 3), (grid.rows(), grid.cols()));
+        assert_eq!('f', grid[(1, 2)]);
+        assert_eq!(Some(&'d'), grid.get(1, 0));
+        assert_eq!(None, grid.get(-1, 0));
+        assert_eq!(None, grid.get(0, 3));
+        assert_eq!('e', grid[Point::new(1, 1)]);
+        assert_eq!(None, grid.at(Point::new(2, 0)));
+        assert_eq!(INPUT, grid.to_string());
+
+        let err = "abc\nde\n".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(ParseGridError::Ragged { line: 2, expected: 3, found: 2 }, err);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = INPUT.parse::<Grid<char>>().expect("failed to parse grid");
+        let four: String = grid.neighbors4(0, 0).map(|(_, c)| c).collect();
+        assert_eq!("bd", four);
+        let eight: String = grid.neighbors8(0, 1).map(|(_, c)| c).collect();
+        assert_eq!("acdef", eight);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = "abc\ndef\nghi\n".parse::<Grid<char>>().expect("failed to parse grid");
+        assert_eq!(&['d', 'e', 'f'], grid.row(1));
+        assert_eq!("beh", grid.column(1).collect::<String>());
+        assert_eq!("aei", grid.diagonal(1, 1).collect::<String>());
+        assert_eq!("bf", grid.diagonal(0, 1).collect::<String>());
+        assert_eq!("ceg", grid.anti_diagonal(1, 1).collect::<String>());
+        assert_eq!("fh", grid.anti_diagonal(2, 1).collect::<String>());
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = INPUT.parse::<Grid<char>>().expect("failed to parse grid");
+        assert_eq!("ad\nbe\ncf\n", grid.transpose().to_string());
+        assert_eq!("da\neb\nfc\n", grid.rotate_clockwise().to_string());
+        assert_eq!("cf\nbe\nad\n", grid.rotate_counter_clockwise().to_string());
+        assert_eq!(grid, grid.rotate_clockwise().rotate_counter_clockwise());
+    }
+}
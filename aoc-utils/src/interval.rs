@@ -0,0 +1,254 @@
+use std::fmt;
+use std::ops::{Add, Range, Sub};
+
+/// A set of values stored as sorted, disjoint half-open ranges.
+///
+/// The ranges are always kept coalesced: no two of them overlap or
+/// touch, and none of them is empty. This makes set operations on
+/// ranges as large as `u64` cheap, since only the endpoints are stored.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord + Copy> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Builds a set from arbitrary ranges, which may overlap, touch,
+    /// be empty or be out of order.
+    pub fn coalesce(ranges: impl IntoIterator<Item = Range<T>>) -> Self {
+        let mut ranges: Vec<Range<T>> = ranges.into_iter().filter(|r| r.start < r.end).collect();
+        ranges.sort_by_key(|r| r.start);
+
+        let mut result: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match result.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => result.push(range),
+            }
+        }
+        Self { ranges: result }
+    }
+
+    /// Adds a range to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&Self::coalesce([range]));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns true if the value is in one of the ranges.
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// Returns the smallest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    /// Iterates over the ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    /// Values that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::coalesce(self.ranges.iter().chain(&other.ranges).cloned())
+    }
+
+    /// Values that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                result.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges: result }
+    }
+
+    /// Values that are in this set but not in the other.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // Skip the ranges of `other` that end before this one starts.
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let cut = &other.ranges[k];
+                if start < cut.start {
+                    result.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                k += 1;
+            }
+            if start < range.end {
+                result.push(start..range.end);
+            }
+        }
+        Self { ranges: result }
+    }
+
+    /// Returns the ranges of the set, further split so that none of the
+    /// given points lies strictly inside a range.
+    pub fn split_at(&self, points: &[T]) -> Vec<Range<T>> {
+        let mut points = points.to_vec();
+        points.sort();
+        points.dedup();
+
+        let mut result = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            let from = points.partition_point(|&p| p <= range.start);
+            for &point in points[from..].iter().take_while(|&&p| p < range.end) {
+                result.push(start..point);
+                start = point;
+            }
+            result.push(start..range.end);
+        }
+        result
+    }
+}
+
+impl<T> IntervalSet<T>
+    where T: Ord + Copy + Default + Add<Output = T> + Sub<Output = T>
+{
+    /// Number of values in the set.
+    pub fn count(&self) -> T {
+        self.ranges.iter().fold(T::default(), |acc, r| acc + (r.end - r.start))
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::coalesce(iter)
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+    use std::collections::BTreeSet;
+    use proptest::prelude::*;
+    use super::IntervalSet;
+
+    /// The values of a set, for comparing against a plain `BTreeSet`.
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn model(ranges: &[Range<u8>]) -> BTreeSet<u8> {
+        ranges.iter().flat_map(|r| r.clone()).collect()
+    }
+
+    fn is_coalesced(set: &IntervalSet<u8>) -> bool {
+        set.iter().all(|r| r.start < r.end)
+            && set.ranges.windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<Range<u8>>> {
+        prop::collection::vec((0u8..64, 0u8..16).prop_map(|(s, l)| s..s + l), 0..8)
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let set = IntervalSet::coalesce([5..8, 0..2, 2..3, 7..10, 12..12]);
+        assert_eq!(vec![0..3, 5..10], set.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(8, set.count());
+        assert!(set.contains(9));
+        assert!(!set.contains(3));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let set = IntervalSet::coalesce([0..10, 20..30]);
+        assert_eq!(vec![0..5, 5..10, 20..25, 25..30], set.split_at(&[25, 5, 0, 10, 40]));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_coalesce(a in ranges()) {
+            let set = IntervalSet::coalesce(a.clone());
+            prop_assert!(is_coalesced(&set));
+            prop_assert_eq!(model(&a), values(&set));
+        }
+
+        #[test]
+        fn prop_union(a in ranges(), b in ranges()) {
+            let set = IntervalSet::coalesce(a.clone()).union(&IntervalSet::coalesce(b.clone()));
+            prop_assert!(is_coalesced(&set));
+            prop_assert_eq!(model(&a).union(&model(&b)).cloned().collect::<BTreeSet<_>>(), values(&set));
+        }
+
+        #[test]
+        fn prop_intersection(a in ranges(), b in ranges()) {
+            let set = IntervalSet::coalesce(a.clone()).intersection(&IntervalSet::coalesce(b.clone()));
+            prop_assert!(is_coalesced(&set));
+            prop_assert_eq!(model(&a).intersection(&model(&b)).cloned().collect::<BTreeSet<_>>(), values(&set));
+        }
+
+        #[test]
+        fn prop_difference(a in ranges(), b in ranges()) {
+            let set = IntervalSet::coalesce(a.clone()).difference(&IntervalSet::coalesce(b.clone()));
+            prop_assert!(is_coalesced(&set));
+            prop_assert_eq!(model(&a).difference(&model(&b)).cloned().collect::<BTreeSet<_>>(), values(&set));
+        }
+
+        #[test]
+        fn prop_split_at(a in ranges(), points in prop::collection::vec(0u8..80, 0..6)) {
+            let set = IntervalSet::coalesce(a.clone());
+            let pieces = set.split_at(&points);
+            prop_assert_eq!(model(&a), model(&pieces));
+            for piece in &pieces {
+                prop_assert!(piece.start < piece.end);
+                prop_assert!(points.iter().all(|&p| p <= piece.start || p >= piece.end));
+            }
+        }
+
+        #[test]
+        fn prop_contains(a in ranges(), x in 0u8..80) {
+            let set = IntervalSet::coalesce(a.clone());
+            prop_assert_eq!(model(&a).contains(&x), set.contains(x));
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Utilities shared by the solutions of every year.
+
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod parse;
+pub mod rng;
+pub mod scan;
+pub mod search;
+
+pub use error::PuzzleError;
+pub use geometry::{BoundingBox, Direction, Metric, Point, Vector};
+pub use grid::Grid;
+pub use interval::IntervalSet;
+pub use rng::Rng;
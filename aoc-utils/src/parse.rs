@@ -0,0 +1,520 @@
+//! A small parser combinator toolkit for puzzle inputs.
+//!
+//! A parser is any `Fn(Input) -> PResult<T>`. On success it returns the
+//! parsed value and the remaining input; on failure it returns a
+//! [`ParseError`] with the byte offset at which it gave up. Failing is
+//! cheap, since combinators like [`many0`] and [`opt`] fail all the time;
+//! the line and column are only worked out by [`parse_all`] and
+//! [`parse_lines`], which return a [`PuzzleError`].
+//!
+//! ```
+//! use aoc_utils::parse::{many0, parse_all, preceded, space1, tag, uint};
+//!
+//! let seeds = preceded(tag("seeds:"), many0(preceded(space1, uint::<u64>)));
+//! assert_eq!(vec![79, 14], parse_all(seeds, "seeds: 79 14").unwrap());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::PuzzleError;
+
+/// The input of a parser: the whole source and the current offset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Input<'a> {
+    src: &'a str,
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self { src, offset: 0 }
+    }
+
+    /// The input that has not been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.src[self.offset..]
+    }
+
+    /// Byte offset from the start of the source.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset == self.src.len()
+    }
+
+    /// Consumes `n` bytes, returning them and the remaining input.
+    fn take(self, n: usize) -> (&'a str, Self) {
+        (&self.src[self.offset..self.offset + n], Self { src: self.src, offset: self.offset + n })
+    }
+
+    /// Returns an error at the current position.
+    pub fn error<T>(&self, expected: impl Into<String>) -> PResult<'a, T> {
+        Err(ParseError { offset: self.offset, expected: expected.into() })
+    }
+}
+
+/// A parse failure, at a byte offset into the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Byte offset from the start of the source.
+    pub offset: usize,
+    /// Description of what was expected at this position.
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Turns the error into a [`PuzzleError`], with the line and column
+    /// of its offset into `src`.
+    pub fn locate(self, src: &str) -> PuzzleError {
+        self.locate_from(src, 1, 0)
+    }
+
+    /// Like [`ParseError::locate`], but only counts from `line_start`,
+    /// the byte offset at which the 1-based `line` starts. Callers that
+    /// go through the input a line at a time use this to avoid scanning
+    /// everything before the line again for each error.
+    pub fn locate_from(self, src: &str, line: usize, line_start: usize) -> PuzzleError {
+        let before = &src[line_start..self.offset];
+        let line = line + before.matches('\n').count();
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[start..].chars().count() + 1;
+        PuzzleError::new(line, column, self.expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte {}: expected {}", self.offset, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type PResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+/// Runs `parser` on the whole of `src`. Trailing whitespace is allowed,
+/// anything else left over is an error.
+pub fn parse_all<'a, T, P>(parser: P, src: &'a str) -> Result<T, PuzzleError>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    let (value, rest) = parser(Input::new(src)).map_err(|err| err.locate(src))?;
+    let (_, rest) = rest.take(rest.rest().len() - rest.rest().trim_start().len());
+    if !rest.is_empty() {
+        return Err(ParseError { offset: rest.offset(), expected: "end of input".to_string() }.locate(src));
+    }
+    Ok(value)
+}
+
+/// Runs `parser` on every non-blank line of `src` separately, so that
+/// one malformed line does not stop the others from being parsed.
+/// Positions in the errors are still relative to the whole of `src`.
+pub fn parse_lines<'a, T, P>(parser: P, src: &'a str) -> Vec<Result<T, PuzzleError>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    let mut result = Vec::new();
+    let mut offset = 0;
+    for (i, line) in src.split_inclusive('\n').enumerate() {
+        let start = Input { src, offset };
+        offset += line.len();
+        if line.trim().is_empty() {
+            continue;
+        }
+        let parsed = terminated(&parser, end_of_line)(start);
+        result.push(parsed.map(|(value, _)| value).map_err(|err| err.locate_from(src, i + 1, start.offset)));
+    }
+    result
+}
+
+fn describe(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+/// Matches the literal string `t`.
+pub fn tag<'a>(t: &'static str) -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    move |input: Input<'a>| {
+        if input.rest().starts_with(t) {
+            Ok(input.take(t.len()))
+        } else {
+            input.error(describe(t))
+        }
+    }
+}
+
+/// Matches one of the literal strings, trying them in order.
+pub fn one_of<'a>(tags: &'static [&'static str]) -> impl Fn(Input<'a>) -> PResult<'a, &'a str> {
+    move |input: Input<'a>| {
+        match tags.iter().find(|t| input.rest().starts_with(*t)) {
+            Some(t) => Ok(input.take(t.len())),
+            None => input.error(format!("one of {}", tags.iter().map(|t| describe(t)).collect::<Vec<_>>().join(", "))),
+        }
+    }
+}
+
+/// Consumes the longest prefix (possibly empty) whose characters match `f`.
+pub fn take_while<'a, F>(f: F) -> impl Fn(Input<'a>) -> PResult<'a, &'a str>
+    where F: Fn(char) -> bool
+{
+    move |input: Input<'a>| {
+        let n = input.rest().find(|c: char| !f(c)).unwrap_or(input.rest().len());
+        Ok(input.take(n))
+    }
+}
+
+/// Like [`take_while`], but fails unless at least one character matches.
+pub fn take_while1<'a, F>(f: F, expected: &'static str) -> impl Fn(Input<'a>) -> PResult<'a, &'a str>
+    where F: Fn(char) -> bool
+{
+    let p = take_while(f);
+    move |input: Input<'a>| {
+        let (s, rest) = p(input)?;
+        if s.is_empty() {
+            input.error(expected)
+        } else {
+            Ok((s, rest))
+        }
+    }
+}
+
+/// Zero or more spaces or tabs.
+pub fn space0<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    take_while(|c| c == ' ' || c == '\t')(input)
+}
+
+/// One or more spaces or tabs.
+pub fn space1<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    take_while1(|c| c == ' ' || c == '\t', "whitespace")(input)
+}
+
+/// One or more ASCII letters.
+pub fn word<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    take_while1(|c: char| c.is_ascii_alphabetic(), "a word")(input)
+}
+
+/// A line break.
+pub fn newline<'a>(input: Input<'a>) -> PResult<'a, &'a str> {
+    match input.rest() {
+        rest if rest.starts_with("\r\n") => Ok(input.take(2)),
+        rest if rest.starts_with('\n') => Ok(input.take(1)),
+        _ => input.error("end of line"),
+    }
+}
+
+/// The end of the input.
+pub fn eof(input: Input<'_>) -> PResult<'_, ()> {
+    if input.is_empty() {
+        Ok(((), input))
+    } else {
+        input.error("end of input")
+    }
+}
+
+/// The end of a line: optional trailing spaces, then a line break
+/// or the end of the input.
+pub fn end_of_line(input: Input<'_>) -> PResult<'_, ()> {
+    let (_, input) = space0(input)?;
+    if input.is_empty() {
+        return Ok(((), input));
+    }
+    newline(input).map(|(_, rest)| ((), rest))
+}
+
+/// An unsigned integer in base 10.
+pub fn uint<'a, T: FromStr>(input: Input<'a>) -> PResult<'a, T> {
+    let (digits, rest) = take_while1(|c: char| c.is_ascii_digit(), "an integer")(input)?;
+    match digits.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(_) => input.error(format!("an integer that fits in {}", std::any::type_name::<T>())),
+    }
+}
+
+/// A signed integer in base 10, with an optional leading `-` or `+`.
+pub fn int<'a, T: FromStr>(input: Input<'a>) -> PResult<'a, T> {
+    let sign = input.rest().starts_with(['-', '+']) as usize;
+    let (_, after_sign) = input.take(sign);
+    let (digits, rest) = take_while1(|c: char| c.is_ascii_digit(), "an integer")(after_sign)?;
+    let (text, _) = input.take(sign + digits.len());
+    match text.parse() {
+        Ok(value) => Ok((value, rest)),
+        Err(_) => input.error(format!("an integer that fits in {}", std::any::type_name::<T>())),
+    }
+}
+
+pub fn map<'a, T, U, P, F>(p: P, f: F) -> impl Fn(Input<'a>) -> PResult<'a, U>
+    where P: Fn(Input<'a>) -> PResult<'a, T>,
+          F: Fn(T) -> U,
+{
+    move |input: Input<'a>| p(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// Runs `p`, but succeeds without consuming anything if it fails.
+pub fn opt<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Option<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    move |input: Input<'a>| match p(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// Tries `a`, then `b`. If both fail, reports the error that got furthest.
+pub fn either<'a, T, A, B>(a: A, b: B) -> impl Fn(Input<'a>) -> PResult<'a, T>
+    where A: Fn(Input<'a>) -> PResult<'a, T>,
+          B: Fn(Input<'a>) -> PResult<'a, T>,
+{
+    move |input: Input<'a>| {
+        let e1 = match a(input) {
+            Ok(result) => return Ok(result),
+            Err(e) => e,
+        };
+        let e2 = match b(input) {
+            Ok(result) => return Ok(result),
+            Err(e) => e,
+        };
+        if e1.offset == e2.offset {
+            Err(ParseError { expected: format!("{} or {}", e1.expected, e2.expected), ..e1 })
+        } else {
+            Err(if e1.offset > e2.offset { e1 } else { e2 })
+        }
+    }
+}
+
+pub fn pair<'a, T, U, A, B>(a: A, b: B) -> impl Fn(Input<'a>) -> PResult<'a, (T, U)>
+    where A: Fn(Input<'a>) -> PResult<'a, T>,
+          B: Fn(Input<'a>) -> PResult<'a, U>,
+{
+    move |input: Input<'a>| {
+        let (x, input) = a(input)?;
+        let (y, input) = b(input)?;
+        Ok(((x, y), input))
+    }
+}
+
+/// Runs `a` then `b`, keeping only the result of `b`.
+pub fn preceded<'a, T, U, A, B>(a: A, b: B) -> impl Fn(Input<'a>) -> PResult<'a, U>
+    where A: Fn(Input<'a>) -> PResult<'a, T>,
+          B: Fn(Input<'a>) -> PResult<'a, U>,
+{
+    map(pair(a, b), |(_, y)| y)
+}
+
+/// Runs `a` then `b`, keeping only the result of `a`.
+pub fn terminated<'a, T, U, A, B>(a: A, b: B) -> impl Fn(Input<'a>) -> PResult<'a, T>
+    where A: Fn(Input<'a>) -> PResult<'a, T>,
+          B: Fn(Input<'a>) -> PResult<'a, U>,
+{
+    map(pair(a, b), |(x, _)| x)
+}
+
+/// Runs `a`, `b` and `c`, keeping only the result of `b`.
+pub fn delimited<'a, T, U, V, A, B, C>(a: A, b: B, c: C) -> impl Fn(Input<'a>) -> PResult<'a, U>
+    where A: Fn(Input<'a>) -> PResult<'a, T>,
+          B: Fn(Input<'a>) -> PResult<'a, U>,
+          C: Fn(Input<'a>) -> PResult<'a, V>,
+{
+    preceded(a, terminated(b, c))
+}
+
+/// Runs `p` as often as it succeeds.
+pub fn many0<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    move |mut input: Input<'a>| {
+        let mut result = Vec::new();
+        while let Ok((value, rest)) = p(input) {
+            if rest.offset() == input.offset() {
+                break;
+            }
+            result.push(value);
+            input = rest;
+        }
+        Ok((result, input))
+    }
+}
+
+/// Like [`many0`], but fails unless `p` succeeds at least once.
+pub fn many1<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    move |input: Input<'a>| {
+        let (first, mut input) = p(input)?;
+        let mut result = vec![first];
+        while let Ok((value, rest)) = p(input) {
+            if rest.offset() == input.offset() {
+                break;
+            }
+            result.push(value);
+            input = rest;
+        }
+        Ok((result, input))
+    }
+}
+
+/// One or more `item`s, separated by `sep`.
+pub fn separated1<'a, T, U, P, S>(item: P, sep: S) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>,
+          S: Fn(Input<'a>) -> PResult<'a, U>,
+{
+    move |input: Input<'a>| {
+        let (first, mut input) = item(input)?;
+        let mut result = vec![first];
+        loop {
+            let Ok((_, after_sep)) = sep(input) else { break };
+            let (value, rest) = item(after_sep)?;
+            if rest.offset() == input.offset() {
+                break;
+            }
+            result.push(value);
+            input = rest;
+        }
+        Ok((result, input))
+    }
+}
+
+/// A whole line parsed by `p`, including its line break.
+pub fn line<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, T>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    terminated(p, end_of_line)
+}
+
+/// Consecutive non-blank lines, each parsed by `p`, up to the next blank
+/// line or the end of the input.
+pub fn lines<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    let p = line(p);
+    move |mut input: Input<'a>| {
+        let mut result = Vec::new();
+        while !input.is_empty() && !input.rest().starts_with(['\n', '\r']) {
+            let (value, rest) = p(input)?;
+            result.push(value);
+            input = rest;
+        }
+        Ok((result, input))
+    }
+}
+
+/// One or more blank lines.
+pub fn blank_lines(input: Input<'_>) -> PResult<'_, ()> {
+    let (_, rest) = many0(preceded(space0, newline))(input)?;
+    if rest.offset() == input.offset() {
+        input.error("a blank line")
+    } else {
+        Ok(((), rest))
+    }
+}
+
+/// A labelled section: a `label:` line, followed by the lines parsed by
+/// `body`. Returns the label (without the colon) and the body.
+pub fn section<'a, T, P>(body: P) -> impl Fn(Input<'a>) -> PResult<'a, (&'a str, T)>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    let label = line(terminated(take_while1(|c| c != ':' && c != '\n', "a section label"), tag(":")));
+    pair(label, body)
+}
+
+/// Sections parsed by `p`, separated by blank lines. Blank lines after
+/// the last section are consumed.
+pub fn sections<'a, T, P>(p: P) -> impl Fn(Input<'a>) -> PResult<'a, Vec<T>>
+    where P: Fn(Input<'a>) -> PResult<'a, T>
+{
+    move |input: Input<'a>| {
+        let (first, mut input) = p(input)?;
+        let mut result = vec![first];
+        while let Ok((_, rest)) = blank_lines(input) {
+            input = rest;
+            if rest.is_empty() {
+                break;
+            }
+            let (value, rest) = p(rest)?;
+            result.push(value);
+            input = rest;
+        }
+        Ok((result, input))
+    }
+}
+
+/// Exactly `N` integers separated by whitespace, such as one row of a table.
+pub fn row<'a, T: FromStr + Copy + Default, const N: usize>(input: Input<'a>) -> PResult<'a, [T; N]> {
+    let mut result = [T::default(); N];
+    let (_, mut input) = space0(input)?;
+    for (i, cell) in result.iter_mut().enumerate() {
+        if i > 0 {
+            input = space1(input)?.1;
+        }
+        (*cell, input) = int(input)?;
+    }
+    Ok((result, input))
+}
+
+/// Consecutive lines of exactly `N` integers each.
+pub fn table<'a, T: FromStr + Copy + Default, const N: usize>(input: Input<'a>) -> PResult<'a, Vec<[T; N]>> {
+    lines(row::<T, N>)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let src = "seeds: 1 2\n\nmap:\n1 2 x\n";
+        let err = parse_all(pair(line(tag("seeds: 1 2")), preceded(blank_lines, section(table::<u64, 3>))), src)
+            .unwrap_err();
+        assert_eq!((4, 5), (err.line, err.column));
+        assert_eq!("4:5: expected an integer", err.to_string());
+    }
+
+    #[test]
+    fn test_locate() {
+        let src = "ab\ncd\u{e9}\nx";
+        let err = ParseError { offset: 7, expected: "x".to_string() };
+        let located = err.clone().locate(src);
+        assert_eq!((2, 4), (located.line, located.column));
+        let located = err.locate_from(src, 2, 3);
+        assert_eq!((2, 4), (located.line, located.column));
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(Ok(-12), parse_all(int::<i32>, "-12"));
+        assert_eq!(Ok(12), parse_all(int::<i32>, "+12"));
+        assert!(parse_all(uint::<u8>, "256").is_err());
+        assert_eq!(Ok([1, 2, 3]), parse_all(row::<u64, 3>, " 1  2 3"));
+    }
+
+    #[test]
+    fn test_sections() {
+        let src = "a map:\n1 2\n3 4\n\nb map:\n5 6\n";
+        let parsed = parse_all(sections(section(table::<u32, 2>)), src).expect("failed to parse");
+        assert_eq!(vec![("a map", vec![[1, 2], [3, 4]]), ("b map", vec![[5, 6]])], parsed);
+
+        let trailing = parse_all(sections(section(table::<u32, 2>)), "a map:\n1 2\n\n\n");
+        assert_eq!(Ok(vec![("a map", vec![[1, 2]])]), trailing);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let results = parse_lines(preceded(tag("n="), uint::<u32>), "n=1\nn=x\n\nn=3\n");
+        assert_eq!(Ok(1), results[0]);
+        assert_eq!((2, 3), results[1].as_ref().map_err(|e| (e.line, e.column)).unwrap_err());
+        assert_eq!(Ok(3), results[2]);
+    }
+
+    #[test]
+    fn test_many1() {
+        let numbers = many1(preceded(space1, uint::<u32>));
+        assert_eq!(Ok(vec![1, 2]), parse_all(&numbers, " 1 2"));
+        assert_eq!("1:1: expected whitespace", parse_all(&numbers, "").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_either() {
+        let colour = either(tag("red"), tag("blue"));
+        let err = parse_all(&colour, "green").unwrap_err();
+        assert_eq!("1:1: expected \"red\" or \"blue\"", err.to_string());
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::Range;
+
+/// A small, seedable pseudo-random number generator (SplitMix64).
+///
+/// It is neither cryptographically secure nor particularly fast, but the
+/// same seed gives the same sequence on every platform and every version,
+/// which is what generated puzzle inputs need.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed value in `0..n`.
+    ///
+    /// Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        // Lemire's multiply-shift; the bias is negligible for puzzle inputs.
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A uniformly distributed value in the range.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        range.start + self.below(range.end - range.start)
+    }
+
+    /// Returns true with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= p
+    }
+
+    /// A uniformly chosen element of a non-empty slice.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Shuffles the slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+
+    /// `n` distinct values from the range, in random order.
+    ///
+    /// The whole range is materialised, so it should be small.
+    /// Panics if it has fewer than `n` values.
+    pub fn sample(&mut self, range: Range<u64>, n: usize) -> Vec<u64> {
+        let mut values: Vec<u64> = range.collect();
+        assert!(values.len() >= n, "cannot sample {} distinct values from {}", n, values.len());
+        // A partial shuffle: only the first `n` positions are needed.
+        for i in 0..n {
+            let j = i + self.below((values.len() - i) as u64) as usize;
+            values.swap(i, j);
+        }
+        values.truncate(n);
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn test_deterministic() {
+        let a: Vec<u64> = (0..5).scan(Rng::new(7), |rng, _| Some(rng.next_u64())).collect();
+        let b: Vec<u64> = (0..5).scan(Rng::new(7), |rng, _| Some(rng.next_u64())).collect();
+        assert_eq!(a, b);
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((10..20).contains(&rng.range(10..20)));
+        }
+        let sample = rng.sample(0..10, 10);
+        let mut sorted = sample.clone();
+        sorted.sort();
+        assert_eq!((0..10).collect::<Vec<_>>(), sorted);
+    }
+}
@@ -0,0 +1,234 @@
+//! Fast, allocation-free extraction of numbers and sections from inputs
+//! where the structure does not matter, only the numbers in it.
+//!
+//! Unlike the combinators in [`parse`](crate::parse), nothing here
+//! reports malformed input: any byte that is not part of a number is
+//! skipped. That is why the day parsers, such as the cards of 2023 day 4
+//! and the seeds of day 5, stay on the combinators, which say where an
+//! input is malformed. This module is for tools that look at arbitrary
+//! inputs, such as `entry inspect`.
+
+use std::marker::PhantomData;
+
+/// An integer type that can be built one decimal digit at a time.
+pub trait Integer: Copy {
+    /// Whether a `-` directly before the digits makes the number negative.
+    const SIGNED: bool;
+    const ZERO: Self;
+
+    /// Appends a digit, or returns `None` on overflow. Negative numbers
+    /// are built by subtracting, so that the minimum value is reachable.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr => $($t:ty)*) => {$(
+        impl Integer for $t {
+            const SIGNED: bool = $signed;
+            const ZERO: Self = 0;
+
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                let digit = digit as Self;
+                let shifted = self.checked_mul(10)?;
+                if negative { shifted.checked_sub(digit) } else { shifted.checked_add(digit) }
+            }
+        }
+    )*};
+}
+
+impl_integer!(false => u8 u16 u32 u64 u128 usize);
+impl_integer!(true => i8 i16 i32 i64 i128 isize);
+
+/// Iterator over the integers in a string. See [`integers`].
+#[derive(Debug, Clone)]
+pub struct Integers<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    marker: PhantomData<T>,
+}
+
+/// Iterates over every integer in `s`, in order, skipping whatever is
+/// between them. For signed types a `-` directly before a number makes
+/// it negative; for unsigned types it is skipped like any other byte, so
+/// `"3-5"` yields `3, 5` as `u32` but `3, -5` as `i32`.
+///
+/// Panics if a number does not fit in `T`.
+///
+/// ```
+/// use aoc_utils::scan::integers;
+///
+/// let v: Vec<i64> = integers("x=-3, y=14..20").collect();
+/// assert_eq!(vec![-3, 14, 20], v);
+/// ```
+pub fn integers<T: Integer>(s: &str) -> Integers<'_, T> {
+    Integers { bytes: s.as_bytes(), pos: 0, marker: PhantomData }
+}
+
+impl<T: Integer> Iterator for Integers<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let bytes = self.bytes;
+        let start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let negative = T::SIGNED && start > 0 && bytes[start - 1] == b'-';
+
+        let mut value = T::ZERO;
+        let mut end = start;
+        while end < bytes.len() && bytes[end].is_ascii_digit() {
+            value = value
+                .push_digit(bytes[end] - b'0', negative)
+                .unwrap_or_else(|| panic!("integer out of range at byte {}", start));
+            end += 1;
+        }
+        self.pos = end;
+        Some(value)
+    }
+}
+
+/// A run of ASCII digits in a string, with whether a `-` directly
+/// precedes it. See [`digit_runs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitRun<'a> {
+    pub digits: &'a str,
+    pub minus: bool,
+}
+
+impl DigitRun<'_> {
+    /// The run as a `T`, negative if `T` is signed and the run follows a
+    /// `-`, or `None` if it does not fit.
+    pub fn value<T: Integer>(&self) -> Option<T> {
+        let negative = T::SIGNED && self.minus;
+        self.digits.bytes().try_fold(T::ZERO, |value, b| value.push_digit(b - b'0', negative))
+    }
+}
+
+/// Iterator over the runs of digits in a string. See [`digit_runs`].
+#[derive(Debug, Clone)]
+pub struct DigitRuns<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+/// Iterates over every run of digits in `s` as text, so that unlike
+/// [`integers`] a number of any length can be looked at without
+/// overflowing.
+///
+/// ```
+/// use aoc_utils::scan::digit_runs;
+///
+/// let runs: Vec<_> = digit_runs("x=-3, y=99999999999999999999").collect();
+/// assert_eq!(("3", true), (runs[0].digits, runs[0].minus));
+/// assert_eq!(Some(-3), runs[0].value::<i8>());
+/// assert_eq!(None, runs[1].value::<u64>());
+/// ```
+pub fn digit_runs(s: &str) -> DigitRuns<'_> {
+    DigitRuns { s, pos: 0 }
+}
+
+impl<'a> Iterator for DigitRuns<'a> {
+    type Item = DigitRun<'a>;
+
+    fn next(&mut self) -> Option<DigitRun<'a>> {
+        let bytes = self.s.as_bytes();
+        let start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let end = bytes[start..].iter().position(|b| !b.is_ascii_digit()).map_or(bytes.len(), |i| start + i);
+        self.pos = end;
+        Some(DigitRun { digits: &self.s[start..end], minus: start > 0 && bytes[start - 1] == b'-' })
+    }
+}
+
+/// Iterator over the blank-line separated sections of a string.
+/// See [`sections`].
+#[derive(Debug, Clone)]
+pub struct Sections<'a> {
+    rest: &'a str,
+}
+
+/// Splits `s` into sections separated by one or more blank lines (lines
+/// with only whitespace). Each section keeps its inner line breaks but
+/// not the trailing one; empty sections are never yielded.
+///
+/// ```
+/// use aoc_utils::scan::sections;
+///
+/// let v: Vec<&str> = sections("a\nb\n\n  \nc\n").collect();
+/// assert_eq!(vec!["a\nb", "c"], v);
+/// ```
+pub fn sections(s: &str) -> Sections<'_> {
+    Sections { rest: s }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // Skip leading blank lines.
+        loop {
+            let line_end = self.rest.find('\n').map_or(self.rest.len(), |i| i + 1);
+            if line_end == 0 {
+                return None;
+            }
+            if !is_blank(&self.rest[..line_end]) {
+                break;
+            }
+            self.rest = &self.rest[line_end..];
+        }
+
+        let mut end = 0;
+        while end < self.rest.len() {
+            let line_end = self.rest[end..].find('\n').map_or(self.rest.len(), |i| end + i + 1);
+            if is_blank(&self.rest[end..line_end]) {
+                break;
+            }
+            end = line_end;
+        }
+
+        let section = self.rest[..end].trim_end_matches(['\n', '\r']);
+        self.rest = &self.rest[end..];
+        Some(section)
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.bytes().all(|b| b.is_ascii_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digit_runs, integers, sections};
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(vec![3u32, 5, 10], integers("3-5 x10").collect::<Vec<_>>());
+        assert_eq!(vec![3i32, -5, 10], integers("3-5 x10").collect::<Vec<_>>());
+        assert_eq!(vec![i8::MIN, i8::MAX], integers("-128 127").collect::<Vec<i8>>());
+        assert_eq!(Vec::<u8>::new(), integers("no numbers - here").collect::<Vec<_>>());
+        assert_eq!(vec![u64::MAX], integers("18446744073709551615").collect::<Vec<u64>>());
+    }
+
+    #[test]
+    #[should_panic(expected = "integer out of range")]
+    fn test_integers_overflow() {
+        integers::<u8>("1 256").for_each(drop);
+    }
+
+    #[test]
+    fn test_digit_runs() {
+        let long = "1".repeat(50);
+        let input = format!("a-12 {} 7", long);
+        let runs: Vec<_> = digit_runs(&input).collect();
+        assert_eq!(vec!["12", long.as_str(), "7"], runs.iter().map(|run| run.digits).collect::<Vec<_>>());
+        assert_eq!(vec![true, false, false], runs.iter().map(|run| run.minus).collect::<Vec<_>>());
+        assert_eq!(vec![Some(-12), None, Some(7)], runs.iter().map(|run| run.value::<i128>()).collect::<Vec<_>>());
+        assert_eq!(Some(12), runs[0].value::<u8>());
+        assert_eq!(Some(i8::MIN), digit_runs("-128").next().and_then(|run| run.value::<i8>()));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "seeds: 1\r\n\r\na map:\r\n1 2 3\r\n\n\nb map:\n4 5 6";
+        let v: Vec<&str> = sections(input).collect();
+        assert_eq!(vec!["seeds: 1", "a map:\r\n1 2 3", "b map:\n4 5 6"], v);
+        assert_eq!(0, sections("\n \n").count());
+    }
+}
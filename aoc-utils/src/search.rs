@@ -0,0 +1,388 @@
+//! Graph searches over implicit graphs.
+//!
+//! Every search is generic over the state type and takes a closure
+//! that returns the neighbours (and, where it matters, the cost of
+//! stepping to them) of a state, so the same code works for grids,
+//! positions with a facing direction, or any other puzzle state.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// States seen during a search, each with the index of the state it
+/// was first reached from, so that paths can be reconstructed.
+struct Visited<S> {
+    states: Vec<S>,
+    parents: Vec<Option<usize>>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Self {
+        Self { states: Vec::new(), parents: Vec::new(), index: HashMap::new() }
+    }
+
+    /// Adds the state, returning its index and whether it is new.
+    fn insert(&mut self, state: S, parent: Option<usize>) -> (usize, bool) {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let i = self.states.len();
+                self.states.push(entry.key().clone());
+                self.parents.push(parent);
+                entry.insert(i);
+                (i, true)
+            },
+        }
+    }
+
+    fn path_to(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while let Some(parent) = self.parents[i] {
+            path.push(self.states[parent].clone());
+            i = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Finds a shortest path (in number of steps) from `start` to a state
+/// for which `is_goal` returns true. The path includes both ends.
+pub fn bfs<S, N, I, G>(start: S, mut neighbors: N, mut is_goal: G) -> Option<Vec<S>>
+    where S: Clone + Eq + Hash,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = S>,
+          G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(visited.insert(start, None).0);
+
+    while let Some(i) = queue.pop_front() {
+        let state = visited.states[i].clone();
+        if is_goal(&state) {
+            return Some(visited.path_to(i));
+        }
+        for next in neighbors(&state) {
+            let (j, new) = visited.insert(next, Some(i));
+            if new {
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// Finds a cheapest path from `start` to a state for which `is_goal`
+/// returns true, where `neighbors` yields `(state, cost)` pairs with
+/// non-negative costs. Returns the path and its total cost.
+pub fn dijkstra<S, C, N, I, G>(start: S, neighbors: N, is_goal: G) -> Option<(Vec<S>, C)>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output = C>,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = (S, C)>,
+          G: FnMut(&S) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` that estimates the
+/// remaining cost to a goal. The heuristic must never overestimate,
+/// or the returned path may not be the cheapest. It need not be
+/// consistent: a state reached more cheaply after it was expanded is
+/// expanded again.
+pub fn astar<S, C, N, I, H, G>(start: S, mut neighbors: N, mut heuristic: H, mut is_goal: G) -> Option<(Vec<S>, C)>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output = C>,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = (S, C)>,
+          H: FnMut(&S) -> C,
+          G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut costs = vec![C::default()];
+    let mut done = vec![false];
+    let mut heap = BinaryHeap::new();
+
+    let (i, _) = visited.insert(start.clone(), None);
+    heap.push(Reverse((heuristic(&start), C::default(), i)));
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if done[i] || cost > costs[i] {
+            continue;
+        }
+        done[i] = true;
+
+        let state = visited.states[i].clone();
+        if is_goal(&state) {
+            return Some((visited.path_to(i), cost));
+        }
+
+        for (next, step) in neighbors(&state) {
+            let next_cost = cost + step;
+            let (j, new) = visited.insert(next, Some(i));
+            if new {
+                costs.push(next_cost);
+                done.push(false);
+            } else if next_cost >= costs[j] {
+                continue;
+            } else {
+                costs[j] = next_cost;
+                done[j] = false;
+                visited.parents[j] = Some(i);
+            }
+            let estimate = next_cost + heuristic(&visited.states[j]);
+            heap.push(Reverse((estimate, next_cost, j)));
+        }
+    }
+    None
+}
+
+/// Returns every state reachable from `start`, in breadth-first order.
+pub fn flood_fill<S, N, I>(start: S, mut neighbors: N) -> Vec<S>
+    where S: Clone + Eq + Hash,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(visited.insert(start, None).0);
+
+    while let Some(i) = queue.pop_front() {
+        let state = visited.states[i].clone();
+        for next in neighbors(&state) {
+            let (j, new) = visited.insert(next, Some(i));
+            if new {
+                queue.push_back(j);
+            }
+        }
+    }
+    visited.states
+}
+
+/// Splits `states` into groups that are connected through `neighbors`.
+/// The neighbour relation is expected to be symmetric.
+pub fn connected_components<S, N, I>(states: impl IntoIterator<Item = S>, mut neighbors: N) -> Vec<Vec<S>>
+    where S: Clone + Eq + Hash,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+    for state in states {
+        if seen.contains(&state) {
+            continue;
+        }
+        let component = flood_fill(state, &mut neighbors);
+        seen.extend(component.iter().cloned());
+        result.push(component);
+    }
+    result
+}
+
+/// A cycle found by repeatedly applying a step function.
+#[derive(Debug, PartialEq)]
+pub struct Cycle<S> {
+    /// Number of steps before the cycle is entered.
+    pub start: usize,
+    /// Number of steps in the cycle.
+    pub length: usize,
+    /// Every state from the initial one up to the end of the first
+    /// pass through the cycle.
+    pub states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// Returns the state after `n` steps, without taking them.
+    pub fn nth(&self, n: usize) -> &S {
+        if n < self.start {
+            &self.states[n]
+        } else {
+            &self.states[self.start + (n - self.start) % self.length]
+        }
+    }
+}
+
+/// Applies `next` starting from `start` until a state repeats.
+pub fn find_cycle<S, F>(start: S, mut next: F) -> Cycle<S>
+    where S: Clone + Eq + Hash,
+          F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+    loop {
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Cycle { start, length: states.len() - start, states };
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(states.len());
+            },
+        }
+        let following = next(&state);
+        states.push(state);
+        state = following;
+    }
+}
+
+/// Finds a cycle in a directed graph reachable from `start`, returned
+/// as the states along it with the first state repeated at the end.
+pub fn find_graph_cycle<S, N, I>(start: S, mut neighbors: N) -> Option<Vec<S>>
+    where S: Clone + Eq + Hash,
+          N: FnMut(&S) -> I,
+          I: IntoIterator<Item = S>,
+{
+    // Iterative depth-first search; states on the current path are
+    // `true` in `on_path`, and a neighbour on the path closes a cycle.
+    let mut on_path: HashMap<S, bool> = HashMap::new();
+    let mut path: Vec<S> = Vec::new();
+    let mut stack: Vec<(S, Vec<S>)> = Vec::new();
+
+    on_path.insert(start.clone(), true);
+    let next: Vec<S> = neighbors(&start).into_iter().collect();
+    path.push(start.clone());
+    stack.push((start, next));
+
+    while let Some((_, pending)) = stack.last_mut() {
+        let Some(state) = pending.pop() else {
+            let (done, _) = stack.pop().unwrap();
+            path.pop();
+            on_path.insert(done, false);
+            continue;
+        };
+
+        match on_path.get(&state) {
+            Some(true) => {
+                let from = path.iter().position(|s| *s == state).unwrap();
+                let mut cycle = path[from..].to_vec();
+                cycle.push(state);
+                return Some(cycle);
+            },
+            Some(false) => continue,
+            None => {
+                on_path.insert(state.clone(), true);
+                let next: Vec<S> = neighbors(&state).into_iter().collect();
+                path.push(state.clone());
+                stack.push((state, next));
+            },
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+    use super::{astar, bfs, connected_components, dijkstra, find_cycle, find_graph_cycle, flood_fill};
+
+    const MAZE: &str = concat!(
+        "S.#.\n",
+        ".##.\n",
+        "...E\n",
+    );
+
+    fn open_neighbors(grid: &Grid<char>, (row, col): (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbors4(row, col)
+            .filter(|&(_, &c)| c != '#')
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = MAZE.parse::<Grid<char>>().expect("failed to parse grid");
+        let path = bfs((0, 0), |&p| open_neighbors(&grid, p), |&p| grid[p] == 'E')
+            .expect("expected a path");
+        assert_eq!(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)], path);
+
+        assert_eq!(None, bfs((0, 0), |&p| open_neighbors(&grid, p), |&p| grid[p] == 'X'));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going straight from 0 to 3 costs 10, but the detour costs 3.
+        let edges = |&n: &u32| match n {
+            0 => vec![(3, 10), (1, 1)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+        assert_eq!(Some((vec![0, 1, 2, 3], 3)), dijkstra(0, edges, |&n| n == 3));
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = MAZE.parse::<Grid<char>>().expect("failed to parse grid");
+        let goal = (2usize, 3usize);
+        let (path, cost) = astar(
+            (0usize, 0usize),
+            |&p| open_neighbors(&grid, p).into_iter().map(|n| (n, 1)),
+            |&(r, c)| goal.0.abs_diff(r) + goal.1.abs_diff(c),
+            |&p| p == goal,
+        ).expect("expected a path");
+        assert_eq!(5, cost);
+        assert_eq!(6, path.len());
+        assert_eq!(Some(&goal), path.last());
+    }
+
+    #[test]
+    fn test_astar_inconsistent() {
+        // The heuristic overrates 'b', so 'c' is first expanded through
+        // the dearer 'a' and must be expanded again once 'b' finds it.
+        let edges = |&n: &char| -> Vec<(char, u32)> {
+            match n {
+                's' => vec![('a', 1), ('b', 1)],
+                'a' => vec![('c', 4)],
+                'b' => vec![('c', 1)],
+                'c' => vec![('g', 4)],
+                _ => vec![],
+            }
+        };
+        let heuristic = |&n: &char| if n == 'b' { 5 } else { 0 };
+        let (path, cost) = astar('s', edges, heuristic, |&n| n == 'g').expect("expected a path");
+        assert_eq!(6, cost);
+        assert_eq!(vec!['s', 'b', 'c', 'g'], path);
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = "ab.\n..#\n#..\n".parse::<Grid<char>>().expect("failed to parse grid");
+        let walls: Vec<_> = grid.iter().filter(|(_, &c)| c == '#').map(|(p, _)| p).collect();
+        let components = connected_components(walls, |&(r, c)| {
+            grid.neighbors4(r, c).filter(|(_, &c)| c == '#').map(|(p, _)| p).collect::<Vec<_>>()
+        });
+        assert_eq!(2, components.len());
+
+        let open = flood_fill((0, 0), |&p| open_neighbors(&grid, p));
+        assert_eq!(7, open.len());
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+        let cycle = find_cycle(0, |&n| if n == 4 { 2 } else { n + 1 });
+        assert_eq!(2, cycle.start);
+        assert_eq!(3, cycle.length);
+        assert_eq!(vec![0, 1, 2, 3, 4], cycle.states);
+        assert_eq!(&4, cycle.nth(1_000_000_000));
+        assert_eq!(&2, cycle.nth(5));
+    }
+
+    #[test]
+    fn test_find_graph_cycle() {
+        let edges = |&n: &u32| match n {
+            0 => vec![1, 4],
+            1 => vec![2],
+            2 => vec![3],
+            3 => vec![1],
+            _ => vec![],
+        };
+        assert_eq!(Some(vec![1, 2, 3, 1]), find_graph_cycle(0, edges));
+        assert_eq!(None, find_graph_cycle(0, |&n: &u32| if n < 5 { vec![n + 1] } else { vec![] }));
+    }
+}
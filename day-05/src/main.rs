@@ -1,3 +0,0 @@
-fn main() {
-    day_05::run_all();
-}
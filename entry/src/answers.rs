@@ -0,0 +1,50 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use anyhow::{anyhow, Result, Context};
+
+/// Answers are stored next to the inputs they belong to, in
+/// `{input_dir}/{year}/answers.txt`, with one tab-separated
+/// `day, part one, part two` line per day.
+pub fn answers_path(input_dir: impl AsRef<Path>, year: u16) -> PathBuf {
+    input_dir.as_ref().join(year.to_string()).join("answers.txt")
+}
+
+/// Loads the recorded answers, or nothing if none were recorded yet.
+pub fn load(path: &Path) -> Result<BTreeMap<usize, (String, String)>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(e).with_context(|| format!("Could not read answers file: {}", path.display())),
+    };
+
+    let mut result = BTreeMap::new();
+    for (i, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let parts: Vec<&str> = line.split('\t').collect();
+        if parts.len() != 3 {
+            return Err(anyhow!(format!("{}:{}: Expected `day\\tone\\ttwo`", path.display(), i + 1)));
+        }
+        let day = parts[0].parse::<usize>()
+            .with_context(|| format!("{}:{}: Failed to parse day: {}", path.display(), i + 1, parts[0]))?;
+        result.insert(day, (parts[1].to_string(), parts[2].to_string()));
+    }
+    Ok(result)
+}
+
+/// Records the answers for a day, replacing any earlier answers.
+pub fn record(path: &Path, day: usize, answers: &(String, String)) -> Result<()> {
+    let mut all = load(path)?;
+    all.insert(day, answers.clone());
+
+    let contents: String = all
+        .iter()
+        .map(|(day, (one, two))| format!("{day}\t{one}\t{two}\n"))
+        .collect();
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Could not create directory: {}", parent.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("Could not write answers file: {}", path.display()))
+}
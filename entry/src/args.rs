@@ -0,0 +1,43 @@
+use input_downloader::{DEFAULT_INPUT_PATH, DEFAULT_YEAR};
+use anyhow::{Result, Context};
+
+/// The `--year`, `--day` and `--dir` arguments that pick the input of a
+/// single day, shared by the commands that read one.
+pub struct DayArgs {
+    pub year: u16,
+    pub day: Option<usize>,
+    pub input_dir: Option<String>,
+}
+
+impl Default for DayArgs {
+    fn default() -> Self {
+        Self { year: DEFAULT_YEAR, day: None, input_dir: None }
+    }
+}
+
+impl DayArgs {
+    /// Takes `arg`, and its value from `args`, if it is one of the shared
+    /// arguments. Returns `false` for any other argument.
+    pub fn take(&mut self, arg: &str, args: &mut impl Iterator<Item = String>) -> Result<bool> {
+        match arg {
+            "--year" | "-year" | "-y" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                self.year = arg.parse().with_context(|| format!("Failed to parse year: {}", arg))?;
+            },
+            "--day" | "-day" | "-d" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                self.day = Some(arg.parse().with_context(|| format!("Failed to parse day: {}", arg))?);
+            },
+            "--input_dir" | "-input_dir" | "--dir" | "-dir" => {
+                self.input_dir = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// The input directory, or the default one.
+    pub fn input_dir(&self) -> String {
+        self.input_dir.clone().unwrap_or(DEFAULT_INPUT_PATH.to_string())
+    }
+}
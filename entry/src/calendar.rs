@@ -0,0 +1,124 @@
+use std::fs;
+use std::collections::HashMap;
+use input_downloader::{get_event_page, input_path, now, provider_by_name, read_session};
+use input_downloader::{DayStatus, DEFAULT_INPUT_PATH, DEFAULT_PROVIDER, DEFAULT_SESSION_PATH, DEFAULT_YEAR};
+use anyhow::{anyhow, Result, Context};
+
+use crate::answers;
+use crate::registry;
+
+/// `entry calendar [--year Y] [--html page.html] [--dir input] [--cookie C]`
+///
+/// Shows every day of an event as a grid, combining the stars from the
+/// event page with what exists in the workspace. The event page is read
+/// from `--html` if given, and otherwise downloaded with the saved
+/// session cookie. Without either, only the unlock schedule is shown.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut input_dir: Option<String> = None;
+    let mut html: Option<String> = None;
+    let mut cookie: Option<String> = None;
+    let mut year = DEFAULT_YEAR;
+    let mut provider = DEFAULT_PROVIDER.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-year" | "-y" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                year = arg.parse().with_context(|| format!("Failed to parse year: {}", arg))?;
+            },
+            "--provider" | "-provider" | "-p" => {
+                provider = args.next().context(format!("Expected string after argument: {}", arg))?;
+            },
+            "--html" | "-html" => {
+                html = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            "-cookie" | "--cookie" | "-c" => {
+                cookie = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            "--input_dir" | "-input_dir" | "--dir" | "-dir" => {
+                input_dir = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
+        }
+    }
+
+    let input_dir = input_dir.unwrap_or(DEFAULT_INPUT_PATH.to_string());
+    let provider = provider_by_name(&provider)?;
+
+    let page = match (html, cookie.or_else(|| read_session(DEFAULT_SESSION_PATH))) {
+        (Some(path), _) => Some(fs::read_to_string(&path).with_context(|| format!("Could not read file: {}", path))?),
+        (None, Some(cookie)) => Some(get_event_page(provider.as_ref(), year, &cookie)?),
+        (None, None) => None,
+    };
+
+    let stars: HashMap<usize, DayStatus> = match &page {
+        Some(page) => provider.parse_event_page(page).into_iter().collect(),
+        None => HashMap::new(),
+    };
+
+    let recorded = answers::load(&answers::answers_path(&input_dir, year))?;
+    let now = now();
+
+    let days: Vec<Day> = provider
+        .days(year)
+        .into_iter()
+        .map(|day| {
+            let status = match stars.get(&day) {
+                Some(&status) => status,
+                // Without the event page, fall back to the unlock schedule.
+                None if page.is_none() && provider.is_unlocked(year, day, now) => DayStatus::Unlocked,
+                None => DayStatus::Locked,
+            };
+            Day {
+                day,
+                status,
+                solution: registry::find(year, day).is_some(),
+                input: input_path(&input_dir, year, day).is_file(),
+                answers: recorded.contains_key(&day),
+            }
+        })
+        .collect();
+
+    println!("Advent of Code {}", year);
+    print_grid(&days);
+    Ok(())
+}
+
+/// Everything known about a single day.
+struct Day {
+    day: usize,
+    status: DayStatus,
+    solution: bool,
+    input: bool,
+    answers: bool,
+}
+
+impl Day {
+    fn cell(&self) -> String {
+        let stars = match self.status {
+            DayStatus::Locked => "--",
+            DayStatus::Unlocked => "  ",
+            DayStatus::OneStar => "* ",
+            DayStatus::TwoStars => "**",
+        };
+        let flag = |present: bool, c: char| if present { c } else { '.' };
+        format!(
+            "{:2} {} {}{}{}",
+            self.day,
+            stars,
+            flag(self.solution, 'S'),
+            flag(self.input, 'I'),
+            flag(self.answers, 'A'),
+        )
+    }
+}
+
+fn print_grid(days: &[Day]) {
+    for row in days.chunks(5) {
+        let cells: Vec<String> = row.iter().map(Day::cell).collect();
+        println!("    {}", cells.join("   "));
+    }
+    println!();
+    println!("    -- not unlocked, blank unlocked, * one star, ** two stars");
+    println!("    S solution crate, I input, A recorded answers");
+}
@@ -0,0 +1,42 @@
+use anyhow::{anyhow, Result, Context};
+
+use crate::args::DayArgs;
+use crate::registry;
+
+/// `entry dump --day N [--year Y] [--dir input] [--format json]`
+///
+/// Prints the parsed structure of a day's input, so that it can be
+/// inspected with other tools without re-implementing the parser.
+/// JSON is currently the only format.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut target = DayArgs::default();
+    let mut format = "json".to_string();
+
+    while let Some(arg) = args.next() {
+        if target.take(&arg, &mut args)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--format" | "-format" | "-f" => {
+                format = args.next().context(format!("Expected string after argument: {}", arg))?;
+            },
+            _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
+        }
+    }
+
+    if format != "json" {
+        return Err(anyhow!(format!("Unsupported format: {}", format)));
+    }
+
+    let (year, day) = (target.year, target.day.context("Expected a day to dump: --day N")?);
+    let solution = registry::find(year, day)
+        .with_context(|| format!("No solution registered for {} day {}", year, day))?;
+    let dump = solution.dump
+        .with_context(|| format!("The parsed input of {} day {} cannot be dumped", year, day))?;
+
+    let (file_path, input) = registry::read_input(&target.input_dir(), year, day)?;
+
+    let value = dump(&input).map_err(|err| registry::render_input_error(err, &file_path, &input))?;
+    println!("{}", serde_json::to_string_pretty(&value)?);
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anyhow::{anyhow, Result, Context};
+
+use crate::args::DayArgs;
+use crate::registry;
+
+/// `entry explain --day N [--year Y] [--dir input]`
+///
+/// Prints how a day's answers are derived from each line of its input,
+/// to track down which lines a wrong answer comes from.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut target = DayArgs::default();
+
+    while let Some(arg) = args.next() {
+        if !target.take(&arg, &mut args)? {
+            return Err(anyhow!(format!("Invalid command-line argument: {}", arg)));
+        }
+    }
+
+    let (year, day) = (target.year, target.day.context("Expected a day to explain: --day N")?);
+    let solution = registry::find(year, day)
+        .with_context(|| format!("No solution registered for {} day {}", year, day))?;
+    let explain = solution.explain
+        .with_context(|| format!("{} day {} cannot explain its answers", year, day))?;
+
+    let (file_path, input) = registry::read_input(&target.input_dir(), year, day)?;
+
+    let explanation = explain(&input).map_err(|err| registry::render_input_error(err, &file_path, &input))?;
+    print!("{}", explanation);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::Path;
+use input_downloader::DEFAULT_YEAR;
+use anyhow::{anyhow, Result, Context};
+
+use crate::registry;
+
+/// `entry gen --day N [--year Y] [--scale K | --size WxH] [--seed S] [--output file]`
+///
+/// Generates a synthetic input for stress testing a solution, far larger
+/// than the official one if the scale is large. Each day defines what
+/// the scale means; a scale of one is roughly the official input size.
+/// Days whose input is a grid also take an exact `--size` instead.
+/// The input is written to `--output`, or to stdout.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut year = DEFAULT_YEAR;
+    let mut day: Option<usize> = None;
+    let mut scale: Option<usize> = None;
+    let mut size: Option<(usize, usize)> = None;
+    let mut seed = 0;
+    let mut output: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" | "-year" | "-y" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                year = arg.parse().with_context(|| format!("Failed to parse year: {}", arg))?;
+            },
+            "--day" | "-day" | "-d" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                day = Some(arg.parse().with_context(|| format!("Failed to parse day: {}", arg))?);
+            },
+            "--scale" | "-scale" | "-s" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                scale = Some(arg.parse().with_context(|| format!("Failed to parse scale: {}", arg))?);
+            },
+            "--size" | "-size" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                size = Some(parse_size(&arg).with_context(|| format!("Failed to parse size, expected WxH: {}", arg))?);
+            },
+            "--seed" | "-seed" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                seed = arg.parse().with_context(|| format!("Failed to parse seed: {}", arg))?;
+            },
+            "--output" | "-output" | "-o" => {
+                output = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
+        }
+    }
+
+    let day = day.context("Expected a day to generate: --day N")?;
+    if scale == Some(0) {
+        return Err(anyhow!("The scale must be at least 1"));
+    }
+    if scale.is_some() && size.is_some() {
+        return Err(anyhow!("--scale cannot be combined with --size"));
+    }
+    let solution = registry::find(year, day)
+        .with_context(|| format!("No solution registered for {} day {}", year, day))?;
+    let input = match size {
+        Some((width, height)) => {
+            let generate = solution.generate_sized
+                .with_context(|| format!("No sized input generator for {} day {}", year, day))?;
+            generate(seed, width, height)
+        },
+        None => {
+            let generate = solution.generate
+                .with_context(|| format!("No input generator for {} day {}", year, day))?;
+            generate(seed, scale.unwrap_or(1))
+        },
+    };
+    match output {
+        Some(path) => {
+            if let Some(parent) = Path::new(&path).parent() {
+                fs::create_dir_all(parent).with_context(|| format!("Could not create directory: {}", parent.display()))?;
+            }
+            fs::write(&path, &input).with_context(|| format!("Could not write file: {}", path))?;
+            println!("Wrote {} lines to {}", input.lines().count(), path);
+        },
+        None => print!("{}", input),
+    }
+    Ok(())
+}
+
+/// Parses a size such as `300x20`, both at least one.
+fn parse_size(s: &str) -> Option<(usize, usize)> {
+    let (width, height) = s.split_once('x')?;
+    let (width, height) = (width.parse().ok()?, height.parse().ok()?);
+    (width > 0 && height > 0).then_some((width, height))
+}
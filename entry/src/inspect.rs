@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use aoc_utils::scan::{self, DigitRun};
+use anyhow::{anyhow, Result, Context};
+
+use crate::args::DayArgs;
+use crate::registry;
+
+/// `entry inspect --day N [--year Y] [--dir input]`
+///
+/// Describes the shape of an input before any solution exists for it:
+/// line lengths, the characters used, whether it is a grid, how many
+/// blank-line separated sections it has and the range of its integers.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut target = DayArgs::default();
+
+    while let Some(arg) = args.next() {
+        if !target.take(&arg, &mut args)? {
+            return Err(anyhow!(format!("Invalid command-line argument: {}", arg)));
+        }
+    }
+
+    let (year, day) = (target.year, target.day.context("Expected a day to inspect: --day N")?);
+    let (file_path, input) = registry::read_input(&target.input_dir(), year, day)?;
+
+    println!("{}", file_path.display());
+    print_lines(&input);
+    println!("    Sections:   {}", scan::sections(&input).count());
+    print_integers(&input);
+    print_characters(&input);
+    Ok(())
+}
+
+fn print_lines(input: &str) {
+    let lengths: Vec<usize> = input.lines().map(|line| line.chars().count()).collect();
+    let blank = input.lines().filter(|line| line.trim().is_empty()).count();
+    println!("    Lines:      {} ({} blank)", lengths.len(), blank);
+
+    let filled: Vec<usize> = lengths.into_iter().filter(|&n| n > 0).collect();
+    let (Some(min), Some(max)) = (filled.iter().min(), filled.iter().max()) else {
+        return;
+    };
+    println!("    Length:     {}..={} characters", min, max);
+
+    if min == max && blank == 0 && filled.len() > 1 {
+        println!("    Grid:       yes, {} rows by {} columns", filled.len(), min);
+    } else {
+        println!("    Grid:       no");
+    }
+}
+
+fn print_integers(input: &str) {
+    for line in describe_integers(input) {
+        println!("    {}", line);
+    }
+}
+
+/// Describes the integers of an input. The digits are read as text, so
+/// that a number too long for any integer type, such as an input that is
+/// one long string of digits, is counted instead of parsed.
+fn describe_integers(input: &str) -> Vec<String> {
+    let runs: Vec<DigitRun> = scan::digit_runs(input).collect();
+    let lengths = runs.iter().map(|run| run.digits.len());
+    let (Some(shortest), Some(longest)) = (lengths.clone().min(), lengths.max()) else {
+        return vec!["Integers:   none".to_string()];
+    };
+
+    let mut result = Vec::new();
+    let unsigned = runs.iter().filter_map(|run| run.value::<u128>()).map(|n| i128::try_from(n).unwrap_or(i128::MAX));
+    match integer_range(unsigned) {
+        Some((count, min, max)) => {
+            result.push(format!("Integers:   {} between {} and {}, fits in {}", count, min, max, smallest_type(min, max)));
+        },
+        None => result.push("Integers:   none that fit in u128".to_string()),
+    }
+    result.push(format!("Digits:     {}..={} per integer", shortest, longest));
+
+    let too_long = runs.iter().filter(|run| run.value::<u128>().is_none()).count();
+    if too_long > 0 {
+        result.push(format!("Too long:   {} do not fit in u128", too_long));
+    }
+
+    // A `-` may be a minus sign or just a separator, as in `3-5`, so the
+    // signed reading is only reported separately.
+    let signed: Vec<i128> = runs.iter().filter_map(|run| run.value::<i128>()).collect();
+    if let Some((_, min, max)) = integer_range(signed.iter().copied()).filter(|&(_, min, _)| min < 0) {
+        let negative = signed.iter().filter(|&&n| n < 0).count();
+        result.push(format!("Signed:     {} with a leading '-', then between {} and {}, fits in {}", negative, min, max, smallest_type(min, max)));
+    }
+    result
+}
+
+/// The number of integers and their minimum and maximum.
+fn integer_range(integers: impl Iterator<Item = i128>) -> Option<(usize, i128, i128)> {
+    integers.fold(None, |acc, n| match acc {
+        None => Some((1, n, n)),
+        Some((count, min, max)) => Some((count + 1, min.min(n), max.max(n))),
+    })
+}
+
+/// The smallest primitive integer type that holds both values.
+fn smallest_type(min: i128, max: i128) -> &'static str {
+    let types: [(&str, i128, i128); 8] = [
+        ("u8", 0, u8::MAX.into()),
+        ("i8", i8::MIN.into(), i8::MAX.into()),
+        ("u16", 0, u16::MAX.into()),
+        ("i16", i16::MIN.into(), i16::MAX.into()),
+        ("u32", 0, u32::MAX.into()),
+        ("i32", i32::MIN.into(), i32::MAX.into()),
+        ("u64", 0, u64::MAX.into()),
+        ("i64", i64::MIN.into(), i64::MAX.into()),
+    ];
+    types
+        .iter()
+        .find(|&&(_, lo, hi)| lo <= min && max <= hi)
+        .map_or("i128", |&(name, _, _)| name)
+}
+
+fn print_characters(input: &str) {
+    let mut histogram: BTreeMap<char, usize> = BTreeMap::new();
+    for c in input.chars().filter(|&c| c != '\n' && c != '\r') {
+        *histogram.entry(c).or_default() += 1;
+    }
+
+    // Most frequent first, so the "background" characters lead.
+    let mut counts: Vec<(char, usize)> = histogram.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    println!("    Characters: {} distinct", counts.len());
+    for row in counts.chunks(6) {
+        let cells: Vec<String> = row.iter().map(|(c, n)| format!("{:>6} {:7}", format!("{:?}", c), n)).collect();
+        println!("        {}", cells.join(" "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::describe_integers;
+
+    #[test]
+    fn test_describe_integers() {
+        assert_eq!(vec!["Integers:   none"], describe_integers("abc\n"));
+        assert_eq!(vec![
+            "Integers:   3 between 3 and 300, fits in u16",
+            "Digits:     1..=3 per integer",
+            "Signed:     1 with a leading '-', then between -5 and 300, fits in i16",
+        ], describe_integers("3-5 x300\n"));
+    }
+
+    #[test]
+    fn test_describe_long_integers() {
+        let digits = "1234567890".repeat(5);
+        assert_eq!(vec![
+            "Integers:   none that fit in u128",
+            "Digits:     50..=50 per integer",
+            "Too long:   1 do not fit in u128",
+        ], describe_integers(&format!("{}\n", digits)));
+
+        assert_eq!(vec![
+            "Integers:   1 between 7 and 7, fits in u8",
+            "Digits:     1..=50 per integer",
+            "Too long:   1 do not fit in u128",
+        ], describe_integers(&format!("{} 7\n", digits)));
+    }
+}
@@ -0,0 +1,45 @@
+use std::io;
+use std::path::Path;
+use input_downloader::{save_session, Browser, DEFAULT_SESSION_PATH};
+use anyhow::{anyhow, Result, Context};
+
+/// `entry login [--from-browser firefox|chromium] [--profile cookies.sqlite]`
+///
+/// Saves the session cookie so that later runs can download inputs
+/// without asking for it. With `--from-browser`, the cookie is read
+/// from the browser's local cookie database instead of being pasted.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut browser: Option<Browser> = None;
+    let mut profile: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from-browser" | "-from-browser" | "-b" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                browser = Some(arg.parse()?);
+            },
+            "--profile" | "-profile" => {
+                profile = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
+        }
+    }
+
+    let cookie = match (browser, profile) {
+        (Some(browser), Some(profile)) => browser
+            .read_session_cookie(Path::new(&profile))?
+            .with_context(|| format!("No session cookie in cookie database: {}", profile))?,
+        (Some(browser), None) => browser.session_cookie()?,
+        (None, Some(_)) => return Err(anyhow!("--profile requires --from-browser")),
+        (None, None) => {
+            let mut cookie = String::new();
+            println!("Please enter session cookie:");
+            io::stdin().read_line(&mut cookie).context("Could not read input")?;
+            cookie.trim().to_string()
+        },
+    };
+
+    save_session(DEFAULT_SESSION_PATH, &cookie)?;
+    println!("Saved session cookie to {}", DEFAULT_SESSION_PATH);
+    Ok(())
+}
@@ -1,54 +1,119 @@
 use std::io;
-use std::mem;
 use std::env;
 use std::path::Path;
-use input_downloader::{get_all_inputs, DEFAULT_INPUT_PATH};
+use input_downloader::{get_all_inputs, input_path, provider_by_name, read_session};
+use input_downloader::{DEFAULT_INPUT_PATH, DEFAULT_PROVIDER, DEFAULT_SESSION_PATH, DEFAULT_YEAR};
 use anyhow::{anyhow, Result, Context};
 
-use day_01;
-use day_02;
-use day_03;
-use day_04;
+mod answers;
+mod args;
+mod calendar;
+mod dump;
+mod explain;
+mod generate;
+mod inspect;
+mod login;
+mod query;
+mod registry;
+mod run;
 
 fn main() -> Result<()> {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("run") => {
+            args.next();
+            run::main(args)
+        },
+        Some("calendar") => {
+            args.next();
+            calendar::main(args)
+        },
+        Some("dump") => {
+            args.next();
+            dump::main(args)
+        },
+        Some("explain") => {
+            args.next();
+            explain::main(args)
+        },
+        Some("gen") => {
+            args.next();
+            generate::main(args)
+        },
+        Some("inspect") => {
+            args.next();
+            inspect::main(args)
+        },
+        Some("query") => {
+            args.next();
+            query::main(args)
+        },
+        Some("login") => {
+            args.next();
+            login::main(args)
+        },
+        _ => run_all(args),
+    }
+}
+
+/// Runs every registered solution for a year, downloading the
+/// inputs first if they are missing.
+fn run_all(mut args: impl Iterator<Item = String>) -> Result<()> {
     let mut input_dir: Option<String> = None;
     let mut cookie = String::new();
+    let mut year = DEFAULT_YEAR;
+    let mut provider = DEFAULT_PROVIDER.to_string();
 
-    let mut args = env::args().skip(1);
     while let Some(arg) = args.next() {
         match arg.as_str() {
             "-cookie" | "--cookie" | "-c" => {
                 cookie = args.next().context(format!("Expected string after argument: {}", arg))?;
             },
+            "--year" | "-year" | "-y" => {
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                year = arg.parse().with_context(|| format!("Failed to parse year: {}", arg))?;
+            },
+            "--provider" | "-provider" | "-p" => {
+                provider = args.next().context(format!("Expected string after argument: {}", arg))?;
+            },
             "--input_dir" | "-input_dir" | "--dir" | "-dir" => {
                 let new = args.next().context(format!("Expected string after argument: {}", arg))?;
-                
-                if let Some(old) = mem::replace(&mut input_dir, Some(new.clone())) {
+
+                if let Some(old) = input_dir.replace(new.clone()) {
                     return Err(anyhow!(format!("Duplicate input directory flag: {} and {}", new, old)));
                 }
-                
+
             },
             _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
         }
     }
 
     let input_dir = input_dir.unwrap_or(DEFAULT_INPUT_PATH.to_string());
+    let provider = provider_by_name(&provider)?;
+
+    let solutions: Vec<_> = registry::for_year(year).collect();
+    if solutions.is_empty() {
+        return Err(anyhow!(format!("No solutions registered for year {}", year)));
+    }
+
+    if !Path::new(&input_dir).join(year.to_string()).exists() {
+        if cookie.is_empty() {
+            cookie = read_session(DEFAULT_SESSION_PATH).unwrap_or_default();
+        }
 
-    if !Path::new(&input_dir).exists() {
         if cookie.is_empty() {
             println!("Please enter session cookie:");
             io::stdin().read_line(&mut cookie).context("Could not read input")?;
             cookie = cookie.trim().to_string();
         }
 
-        let year = "2023".to_string();
-        get_all_inputs((1..=4).collect(), &year, &cookie, &input_dir)?;
+        let days = solutions.iter().map(|solution| solution.day).collect();
+        get_all_inputs(provider.as_ref(), days, year, &cookie, &input_dir)?;
     }
 
-    day_01::run_all();
-    day_02::run_all();
-    day_03::run_all();
-    day_04::run_all();
+    for solution in solutions {
+        run::run_one(solution, &solution.solve, &input_path(&input_dir, solution.year, solution.day))?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,43 @@
+use anyhow::{anyhow, Result, Context};
+
+use crate::args::DayArgs;
+use crate::registry::{self, InvalidQuery};
+
+/// `entry query --day N [--year Y] [--dir input] <query>`
+///
+/// Answers a question about the parsed input of a day, such as
+/// `entry query --day 2 'games where max(red) > 10'`, for exploring the
+/// data without writing a new function for every question.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut target = DayArgs::default();
+    let mut query: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        if target.take(&arg, &mut args)? {
+            continue;
+        }
+        if query.is_some() || arg.starts_with('-') {
+            return Err(anyhow!(format!("Invalid command-line argument: {}", arg)));
+        }
+        query = Some(arg);
+    }
+
+    let (year, day) = (target.year, target.day.context("Expected a day to query: --day N")?);
+    let query = query.context("Expected a query, such as 'count where possible'")?;
+    let solution = registry::find(year, day)
+        .with_context(|| format!("No solution registered for {} day {}", year, day))?;
+    let answer = solution.query
+        .with_context(|| format!("{} day {} cannot be queried", year, day))?;
+
+    let (file_path, input) = registry::read_input(&target.input_dir(), year, day)?;
+
+    let answer = answer(&input, &query).map_err(|err| match err.downcast::<InvalidQuery>() {
+        Ok(InvalidQuery(err)) => {
+            eprintln!("{}", err.render(&query));
+            anyhow!(format!("Invalid query: {}", query))
+        },
+        Err(err) => registry::render_input_error(err, &file_path, &input),
+    })?;
+    println!("{}", answer);
+    Ok(())
+}
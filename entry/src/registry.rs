@@ -0,0 +1,185 @@
+use std::fmt;
+use std::fs;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use input_downloader::input_path;
+use anyhow::{anyhow, Result, Context};
+use aoc_utils::PuzzleError;
+use serde_json::{json, Value};
+
+/// A solution for a single day of a single event.
+///
+/// Solution crates are named `aoc{year}-day-{day}` and live under
+/// a directory per year, so several events can share the workspace.
+pub struct Solution {
+    pub year: u16,
+    pub day: usize,
+    /// Solves both parts for the contents of an input file.
+    pub solve: fn(&str) -> Answers,
+    /// Returns a solver that uses a custom bag of cubes, given as
+    /// `red=12,green=13,blue=14`, for days that have one.
+    pub solve_with_bag: Option<BagSolver>,
+    /// Parses an input file into a JSON value, for days whose parsed
+    /// structures are serializable.
+    pub dump: Option<fn(&str) -> Result<Value>>,
+    /// Generates a valid input from a seed and a scale, for stress tests.
+    pub generate: Option<fn(u64, usize) -> String>,
+    /// Generates a grid input of a given width and height from a seed.
+    pub generate_sized: Option<fn(u64, usize, usize) -> String>,
+    /// Describes how the answers are derived from each line of an input,
+    /// for debugging a wrong answer.
+    pub explain: Option<fn(&str) -> Result<String>>,
+    /// Answers a query, such as `sum id where possible`, about an input.
+    /// A malformed query fails with [`InvalidQuery`].
+    pub query: Option<fn(&str, &str) -> Result<String>>,
+    /// Solves both parts while reading the input a line at a time, for
+    /// inputs too large to hold in memory.
+    pub stream: Option<StreamSolver>,
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution {
+        year: 2023,
+        day: 1,
+        solve: aoc2023_day_01::solve,
+        solve_with_bag: None,
+        dump: None,
+        generate: Some(aoc2023_day_01::generate),
+        generate_sized: None,
+        explain: Some(|input| Ok(aoc2023_day_01::explain(input, &aoc2023_day_01::DigitLexicon::english())?)),
+        query: None,
+        stream: None,
+    },
+    Solution {
+        year: 2023,
+        day: 2,
+        solve: |input| both(aoc2023_day_02::solve(input)),
+        solve_with_bag: Some(|bag| {
+            let bag = aoc2023_day_02::parse_bag(bag).map_err(|err| anyhow!(format!("Failed to parse bag: {}: {}", bag, err)))?;
+            Ok(Box::new(move |input| both(aoc2023_day_02::solve_with_bag(input, &bag))))
+        }),
+        dump: Some(|input| {
+            let games = aoc2023_day_02::parse_strict(input).map_err(|mut errors| errors.remove(0))?;
+            Ok(serde_json::to_value(games)?)
+        }),
+        generate: Some(aoc2023_day_02::generate),
+        generate_sized: None,
+        explain: None,
+        query: Some(|input, query| {
+            let query: aoc2023_day_02::Query = query.parse().map_err(InvalidQuery)?;
+            let games = aoc2023_day_02::parse_strict(input).map_err(|mut errors| errors.remove(0))?;
+            Ok(query.eval(&games).to_string())
+        }),
+        stream: None,
+    },
+    Solution {
+        year: 2023,
+        day: 3,
+        solve: |input| both(aoc2023_day_03::solve(input)),
+        solve_with_bag: None,
+        dump: Some(|input| Ok(serde_json::to_value(input.parse::<aoc2023_day_03::Graph>()?)?)),
+        generate: Some(aoc2023_day_03::generate),
+        generate_sized: Some(aoc2023_day_03::generate_sized),
+        explain: None,
+        query: None,
+        stream: Some(|reader| {
+            let (one, two) = aoc2023_day_03::Stream::new().solve_reader(reader)?;
+            Ok((one.to_string(), two.to_string()))
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 4,
+        solve: |input| both(aoc2023_day_04::solve(input)),
+        solve_with_bag: None,
+        dump: None,
+        generate: Some(aoc2023_day_04::generate),
+        generate_sized: None,
+        explain: None,
+        query: None,
+        stream: None,
+    },
+    Solution {
+        year: 2023,
+        day: 5,
+        solve: |input| both(aoc2023_day_05::solve(input)),
+        solve_with_bag: None,
+        dump: Some(|input| {
+            let seeds = aoc2023_day_05::parse_seeds_part_one(input.lines().next().unwrap_or(""))?;
+            let tables = aoc2023_day_05::parse_transition_tables(input)?;
+            Ok(json!({ "seeds": seeds, "tables": tables }))
+        }),
+        generate: Some(aoc2023_day_05::generate),
+        generate_sized: None,
+        explain: None,
+        query: None,
+        stream: None,
+    },
+];
+
+/// A malformed query, with the position in the query rather than in the
+/// input file.
+#[derive(Debug)]
+pub struct InvalidQuery(pub PuzzleError);
+
+impl fmt::Display for InvalidQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid query: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidQuery {}
+
+/// Returns all registered solutions for the given year, ordered by day.
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS.iter().filter(move |solution| solution.year == year)
+}
+
+/// The answer to each part, or why the input has none.
+pub type Answers = (Result<String, PuzzleError>, Result<String, PuzzleError>);
+
+/// Solves both parts of an input, possibly with options from the
+/// command line.
+pub type Solver = dyn Fn(&str) -> Answers;
+
+/// Makes a solver for a custom bag of cubes, or fails if the bag is
+/// malformed.
+pub type BagSolver = fn(&str) -> Result<Box<Solver>>;
+
+/// Solves both parts of an input read a line at a time.
+pub type StreamSolver = fn(&mut dyn BufRead) -> Result<(String, String)>;
+
+/// The answers of a day that solves both parts at once, so that a
+/// malformed input fails both.
+fn both(answers: Result<(String, String), PuzzleError>) -> Answers {
+    match answers {
+        Ok((one, two)) => (Ok(one), Ok(two)),
+        Err(err) => (Err(err.clone()), Err(err)),
+    }
+}
+
+/// Looks up the solution for a single day.
+pub fn find(year: u16, day: usize) -> Option<&'static Solution> {
+    for_year(year).find(|solution| solution.day == day)
+}
+
+/// Reads the input file of a day, returning its path and contents.
+pub fn read_input(input_dir: &str, year: u16, day: usize) -> Result<(PathBuf, String)> {
+    let file_path = input_path(input_dir, year, day);
+    let input = fs::read_to_string(&file_path)
+        .with_context(|| format!("Could not read input file: {}", file_path.display()))?;
+    Ok((file_path, input))
+}
+
+/// Prints a [`PuzzleError`] against the input it points into, and
+/// replaces it with a shorter error naming the file. Other errors are
+/// returned as they are.
+pub fn render_input_error(err: anyhow::Error, file_path: &Path, input: &str) -> anyhow::Error {
+    match err.downcast::<PuzzleError>() {
+        Ok(err) => {
+            eprintln!("{}", err.with_file(file_path).render(input));
+            anyhow!(format!("Malformed input file: {}", file_path.display()))
+        },
+        Err(err) => err,
+    }
+}
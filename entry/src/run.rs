@@ -0,0 +1,287 @@
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use input_downloader::input_path;
+use anyhow::{anyhow, Result, Context};
+use aoc_utils::PuzzleError;
+
+use crate::answers;
+use crate::args::DayArgs;
+use crate::registry::{self, Answers, Solution, Solver};
+
+/// `entry run --day N [--year Y] [--dir input] [--inputs dir/] [--record] [--bag red=12,green=13,blue=14] [--stream]`
+///
+/// Runs a single day. With `--record`, the answers are saved to the
+/// answers file of the year. `--bag` replaces the bag of cubes of the
+/// days that have one. With `--stream`, days that can solve their input
+/// a line at a time read it that way instead of loading the whole file.
+///
+/// With `--inputs`, the day is run against every file in the directory
+/// (for example inputs from other accounts) and the answers and runtimes
+/// are tabulated, so it is easy to spot a solution that only works for
+/// one particular input.
+pub fn main(mut args: impl Iterator<Item = String>) -> Result<()> {
+    let mut target = DayArgs::default();
+    let mut inputs: Option<String> = None;
+    let mut record = false;
+    let mut bag: Option<String> = None;
+    let mut stream = false;
+
+    while let Some(arg) = args.next() {
+        if target.take(&arg, &mut args)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--inputs" | "-inputs" => {
+                inputs = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            "--record" | "-record" => {
+                record = true;
+            },
+            "--bag" | "-bag" => {
+                bag = Some(args.next().context(format!("Expected string after argument: {}", arg))?);
+            },
+            "--stream" | "-stream" => {
+                stream = true;
+            },
+            _ => return Err(anyhow!(format!("Invalid command-line argument: {}", arg))),
+        }
+    }
+
+    let (year, day) = (target.year, target.day.context("Expected a day to run: --day N")?);
+    // The answers file holds the answers to the real puzzle, which a
+    // custom bag changes.
+    if record && bag.is_some() {
+        return Err(anyhow!("--record cannot be combined with --bag"));
+    }
+    if stream && (bag.is_some() || inputs.is_some()) {
+        return Err(anyhow!("--stream cannot be combined with --bag or --inputs"));
+    }
+    let solution = registry::find(year, day)
+        .with_context(|| format!("No solution registered for {} day {}", year, day))?;
+    let solve: Box<Solver> = match bag {
+        Some(bag) => {
+            let solve_with_bag = solution.solve_with_bag
+                .with_context(|| format!("{} day {} does not take a bag", year, day))?;
+            solve_with_bag(&bag)?
+        },
+        None => Box::new(solution.solve),
+    };
+
+    if let Some(dir) = inputs {
+        if record {
+            return Err(anyhow!("--record cannot be combined with --inputs"));
+        }
+        return run_many(solution, &solve, Path::new(&dir));
+    }
+
+    let input_dir = target.input_dir();
+    let answers = match stream {
+        true => run_stream(solution, &input_path(&input_dir, year, day))?,
+        false => run_one(solution, &solve, &input_path(&input_dir, year, day))?,
+    };
+    if record {
+        let (Some(one), Some(two)) = answers else {
+            return Err(anyhow!("Not recording answers: a part has no answer"));
+        };
+        let path = answers::answers_path(&input_dir, year);
+        answers::record(&path, day, &(one, two))?;
+        println!("Recorded answers in {}", path.display());
+    }
+    Ok(())
+}
+
+/// Runs the solution against a single input and prints both answers,
+/// and the diagnostic of a part that rejected the input. Fails if both
+/// parts did.
+pub fn run_one(solution: &Solution, solve: &Solver, file_path: &Path) -> Result<(Option<String>, Option<String>)> {
+    let input = fs::read_to_string(file_path)
+        .with_context(|| format!("Could not read input file: {}", file_path.display()))?;
+
+    let answers = solve(&input);
+    if answers.0.is_err() || answers.1.is_err() {
+        eprintln!("{}", render_failures(&answers, file_path, &input));
+    }
+    let (one, two) = (answers.0.ok(), answers.1.ok());
+    if one.is_none() && two.is_none() {
+        return Err(anyhow!(format!("Malformed input file: {}", file_path.display())));
+    }
+    println!("Day {:02}", solution.day);
+    println!("    Part One: {}", one.as_deref().unwrap_or("no answer"));
+    println!("    Part Two: {}", two.as_deref().unwrap_or("no answer"));
+    Ok((one, two))
+}
+
+/// Like `run_one`, but reads the input a line at a time.
+fn run_stream(solution: &Solution, file_path: &Path) -> Result<(Option<String>, Option<String>)> {
+    let stream = solution.stream
+        .with_context(|| format!("{} day {} cannot read its input a line at a time", solution.year, solution.day))?;
+    let file = File::open(file_path)
+        .with_context(|| format!("Could not read input file: {}", file_path.display()))?;
+
+    let (one, two) = stream(&mut BufReader::new(file))
+        .with_context(|| format!("Could not solve input file: {}", file_path.display()))?;
+    println!("Day {:02}", solution.day);
+    println!("    Part One: {}", one);
+    println!("    Part Two: {}", two);
+    Ok((Some(one), Some(two)))
+}
+
+/// The diagnostics of the parts that rejected the input. An error shared
+/// by both parts is only rendered once.
+fn render_failures(answers: &Answers, file_path: &Path, input: &str) -> String {
+    let render = |err: &PuzzleError| err.clone().with_file(file_path).render(input);
+    match answers {
+        (Ok(_), Ok(_)) => String::new(),
+        (Err(err), Ok(_)) | (Ok(_), Err(err)) => render(err),
+        (Err(one), Err(two)) if one == two => render(one),
+        (Err(one), Err(two)) => format!("{}\n{}", render(one), render(two)),
+    }
+}
+
+/// The result of running a solution against one input file.
+struct Outcome {
+    name: String,
+    answers: Result<(String, String), Failure>,
+    elapsed: Duration,
+}
+
+/// Why a solution did not produce answers for an input.
+enum Failure {
+    /// The file could not be read as UTF-8 text.
+    Unreadable(String),
+    Panicked(String),
+    /// The input was rejected by at least one part, with the answer of
+    /// each part that accepted it and the rendered diagnostic.
+    Malformed {
+        one: Option<String>,
+        two: Option<String>,
+        diagnostic: String,
+    },
+}
+
+fn run_many(solution: &Solution, solve: &Solver, dir: &Path) -> Result<()> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .with_context(|| format!("Could not read input directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        return Err(anyhow!(format!("No input files in directory: {}", dir.display())));
+    }
+
+    let mut outcomes = Vec::new();
+    {
+        let _silent = SilentPanics::new();
+        for file in &files {
+            let name = file.file_name().map_or(String::new(), |name| name.to_string_lossy().into_owned());
+            let input = match fs::read_to_string(file) {
+                Ok(input) => input,
+                Err(err) => {
+                    let answers = Err(Failure::Unreadable(err.to_string()));
+                    outcomes.push(Outcome { name, answers, elapsed: Duration::ZERO });
+                    continue;
+                },
+            };
+
+            let start = Instant::now();
+            let answers = match panic::catch_unwind(panic::AssertUnwindSafe(|| solve(&input))) {
+                Ok((Ok(one), Ok(two))) => Ok((one, two)),
+                Ok(answers) => {
+                    let diagnostic = render_failures(&answers, file, &input);
+                    Err(Failure::Malformed { one: answers.0.ok(), two: answers.1.ok(), diagnostic })
+                },
+                Err(payload) => Err(Failure::Panicked(panic_message(payload))),
+            };
+            let elapsed = start.elapsed();
+            outcomes.push(Outcome { name, answers, elapsed });
+        }
+    }
+
+    print_table(solution, &outcomes);
+    Ok(())
+}
+
+fn print_table(solution: &Solution, outcomes: &[Outcome]) {
+    let width = outcomes.iter().map(|o| o.name.len()).max().unwrap_or(0).max("Input".len());
+
+    println!("Day {:02} ({}) against {} inputs", solution.day, solution.year, outcomes.len());
+    println!("    {:width$}  {:>20}  {:>20}  {:>12}", "Input", "Part One", "Part Two", "Time");
+
+    let mut failed = 0;
+    for outcome in outcomes {
+        let time = format!("{:.2?}", outcome.elapsed);
+        match &outcome.answers {
+            Ok((one, two)) => {
+                println!("    {:width$}  {:>20}  {:>20}  {:>12}", outcome.name, one, two, time);
+            },
+            Err(Failure::Unreadable(message)) => {
+                failed += 1;
+                println!("    {:width$}  UNREADABLE: {}", outcome.name, message);
+            },
+            Err(Failure::Panicked(message)) => {
+                failed += 1;
+                println!("    {:width$}  PANICKED: {}", outcome.name, message);
+            },
+            Err(Failure::Malformed { one, two, diagnostic }) => {
+                failed += 1;
+                match (one, two) {
+                    (None, None) => println!("    {:width$}  MALFORMED:", outcome.name),
+                    (one, two) => {
+                        let one = one.as_deref().unwrap_or("MALFORMED");
+                        let two = two.as_deref().unwrap_or("MALFORMED");
+                        println!("    {:width$}  {:>20}  {:>20}  {:>12}", outcome.name, one, two, time);
+                    },
+                }
+                for line in diagnostic.lines() {
+                    println!("        {}", line);
+                }
+            },
+        }
+    }
+
+    if failed == 0 {
+        println!("--- All OK ---");
+    } else {
+        println!("--- {} of {} inputs failed ---", failed, outcomes.len());
+    }
+}
+
+/// Silences the panic hook until dropped. The default hook would print
+/// every panic message in the middle of the table; the message is
+/// reported in the table instead.
+struct SilentPanics {
+    hook: Option<PanicHook>,
+}
+
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo) + Sync + Send>;
+
+impl SilentPanics {
+    fn new() -> Self {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        Self { hook: Some(hook) }
+    }
+}
+
+impl Drop for SilentPanics {
+    fn drop(&mut self) {
+        if let Some(hook) = self.hook.take() {
+            panic::set_hook(hook);
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
@@ -0,0 +1,336 @@
+use std::env;
+use std::fs;
+use std::process;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::path::{Path, PathBuf};
+use aes::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use rusqlite::{Connection, OpenFlags, OptionalExtension};
+use anyhow::{anyhow, Result, Context};
+
+const COOKIE_HOST: &str = ".adventofcode.com";
+const COOKIE_NAME: &str = "session";
+
+/// A browser whose local cookie database can be read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Browser {
+    Firefox,
+    Chromium,
+}
+
+impl FromStr for Browser {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "firefox" => Ok(Browser::Firefox),
+            "chromium" => Ok(Browser::Chromium),
+            _ => Err(anyhow!(format!("Unsupported browser: {} (expected firefox or chromium)", s))),
+        }
+    }
+}
+
+impl Browser {
+    /// Returns the cookie databases of every profile found in the
+    /// default (Linux) profile locations.
+    pub fn cookie_databases(&self) -> Vec<PathBuf> {
+        let home = match env::var_os("HOME") {
+            Some(home) => PathBuf::from(home),
+            None => return Vec::new(),
+        };
+
+        let (roots, files): (Vec<PathBuf>, &[&str]) = match self {
+            Browser::Firefox => (
+                vec![
+                    home.join(".mozilla/firefox"),
+                    home.join("snap/firefox/common/.mozilla/firefox"),
+                ],
+                &["cookies.sqlite"],
+            ),
+            Browser::Chromium => (
+                vec![
+                    home.join(".config/chromium"),
+                    home.join("snap/chromium/common/chromium"),
+                ],
+                &["Network/Cookies", "Cookies"],
+            ),
+        };
+
+        let mut result = Vec::new();
+        for root in roots {
+            let Ok(entries) = fs::read_dir(&root) else { continue };
+            let mut profiles: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+            profiles.sort();
+            for profile in profiles {
+                for file in files {
+                    let path = profile.join(file);
+                    if path.is_file() {
+                        result.push(path);
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Reads the Advent of Code session cookie from the first profile
+    /// that has one.
+    pub fn session_cookie(&self) -> Result<String> {
+        let databases = self.cookie_databases();
+        if databases.is_empty() {
+            return Err(anyhow!(format!("Could not find a {:?} profile", self)));
+        }
+        self.first_session_cookie(&databases)
+    }
+
+    /// Reads the session cookie from the first of the databases that has
+    /// one. A database that cannot be read, for example because another
+    /// profile's browser holds it locked, is reported and skipped.
+    fn first_session_cookie(&self, databases: &[PathBuf]) -> Result<String> {
+        for database in databases {
+            match self.read_session_cookie(database) {
+                Ok(Some(cookie)) => return Ok(cookie),
+                Ok(None) => {},
+                Err(err) => eprintln!("Skipping profile: {:#}", err),
+            }
+        }
+
+        Err(anyhow!(format!("No session cookie for {} in any {:?} profile", COOKIE_HOST, self)))
+    }
+
+    /// Reads the session cookie from the given cookie database, if present.
+    pub fn read_session_cookie(&self, database: &Path) -> Result<Option<String>> {
+        // The browser keeps the database locked while it is running,
+        // so the query runs against a copy.
+        let copy = copy_database(database)?;
+        let result = match self {
+            Browser::Firefox => read_firefox(&copy.path),
+            Browser::Chromium => read_chromium(&copy.path),
+        };
+        result.with_context(|| format!("Could not read cookie database: {}", database.display()))
+    }
+}
+
+fn read_firefox(path: &Path) -> Result<Option<String>> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let value = conn
+        .query_row(
+            "SELECT value FROM moz_cookies WHERE host = ?1 AND name = ?2 ORDER BY lastAccessed DESC LIMIT 1",
+            [COOKIE_HOST, COOKIE_NAME],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(value)
+}
+
+fn read_chromium(path: &Path) -> Result<Option<String>> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+    let row: Option<(String, Vec<u8>)> = conn
+        .query_row(
+            "SELECT value, encrypted_value FROM cookies WHERE host_key = ?1 AND name = ?2 ORDER BY last_access_utc DESC LIMIT 1",
+            [COOKIE_HOST, COOKIE_NAME],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()?;
+
+    let Some((value, encrypted)) = row else { return Ok(None) };
+    if !value.is_empty() {
+        return Ok(Some(value));
+    }
+
+    let version: u32 = conn
+        .query_row("SELECT value FROM meta WHERE key = 'version'", [], |row| row.get::<_, String>(0))
+        .optional()?
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+
+    let mut plain = decrypt_chromium(&encrypted)?;
+
+    // Since database version 24 the plaintext is prefixed with the
+    // SHA-256 hash of the cookie's domain.
+    if version >= 24 {
+        if plain.len() < 32 {
+            return Err(anyhow!("Decrypted cookie is shorter than its domain hash"));
+        }
+        plain.drain(..32);
+    }
+
+    String::from_utf8(plain).map(Some).context("Decrypted cookie is not valid UTF-8")
+}
+
+/// Decrypts a `v10` cookie value. On Linux without a keyring, Chromium
+/// derives its AES-128-CBC key from the hard-coded password "peanuts".
+fn decrypt_chromium(encrypted: &[u8]) -> Result<Vec<u8>> {
+    let data = match encrypted.strip_prefix(b"v10") {
+        Some(data) => data,
+        None if encrypted.starts_with(b"v11") => {
+            return Err(anyhow!("Cookie is encrypted with a key from the system keyring, which is not supported"));
+        },
+        None => return Err(anyhow!("Unknown cookie encryption scheme")),
+    };
+
+    let key = chromium_key();
+    let iv = [b' '; 16];
+    let mut buf = data.to_vec();
+    let plain = cbc::Decryptor::<aes::Aes128>::new(&key.into(), &iv.into())
+        .decrypt_padded_mut::<Pkcs7>(&mut buf)
+        .map_err(|_| anyhow!("Could not decrypt cookie"))?;
+    Ok(plain.to_vec())
+}
+
+fn chromium_key() -> [u8; 16] {
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<sha1::Sha1>(b"peanuts", b"saltysalt", 1, &mut key);
+    key
+}
+
+/// A copy of a cookie database in the temporary directory,
+/// removed again when dropped.
+struct DatabaseCopy {
+    dir: PathBuf,
+    path: PathBuf,
+}
+
+impl Drop for DatabaseCopy {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn copy_database(database: &Path) -> Result<DatabaseCopy> {
+    static COPIES: AtomicUsize = AtomicUsize::new(0);
+    let n = COPIES.fetch_add(1, Ordering::Relaxed);
+    let dir = env::temp_dir().join(format!("aoc-cookies-{}-{}", process::id(), n));
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Could not create directory: {}", dir.display()))?;
+
+    let copy = DatabaseCopy { path: dir.join("cookies.sqlite"), dir };
+    fs::copy(database, &copy.path)
+        .with_context(|| format!("Could not copy cookie database: {}", database.display()))?;
+
+    // Recent writes may still live in the write-ahead log.
+    let mut wal = database.as_os_str().to_owned();
+    wal.push("-wal");
+    if Path::new(&wal).is_file() {
+        fs::copy(&wal, copy.dir.join("cookies.sqlite-wal")).context("Could not copy write-ahead log")?;
+    }
+
+    Ok(copy)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::env;
+    use std::process;
+    use std::path::PathBuf;
+    use aes::cipher::{block_padding::Pkcs7, BlockEncryptMut, KeyIvInit};
+    use rusqlite::Connection;
+    use super::{chromium_key, Browser};
+
+    /// A directory of fixture databases for a single test, removed again
+    /// when dropped.
+    struct Fixtures {
+        dir: PathBuf,
+    }
+
+    impl Fixtures {
+        fn new(test: &str) -> Self {
+            let dir = env::temp_dir().join(format!("aoc-fixtures-{}-{}", process::id(), test));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).expect("failed to create fixture directory");
+            Self { dir }
+        }
+
+        fn path(&self, name: &str) -> PathBuf {
+            self.dir.join(name)
+        }
+    }
+
+    impl Drop for Fixtures {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn firefox_fixture(fixtures: &Fixtures, name: &str) -> PathBuf {
+        let path = fixtures.path(name);
+        let conn = Connection::open(&path).expect("failed to create fixture");
+        conn.execute_batch(concat!(
+            "CREATE TABLE moz_cookies (id INTEGER PRIMARY KEY, name TEXT, value TEXT, host TEXT, lastAccessed INTEGER);",
+            "INSERT INTO moz_cookies (name, value, host, lastAccessed) VALUES ('session', 'old', '.adventofcode.com', 1);",
+            "INSERT INTO moz_cookies (name, value, host, lastAccessed) VALUES ('session', 'abc123', '.adventofcode.com', 2);",
+            "INSERT INTO moz_cookies (name, value, host, lastAccessed) VALUES ('session', 'other', '.example.com', 3);",
+        )).expect("failed to fill fixture");
+        path
+    }
+
+    fn chromium_fixture(fixtures: &Fixtures, name: &str, version: u32, scheme: &[u8], plain: &[u8]) -> PathBuf {
+        let iv = [b' '; 16];
+        let mut buf = plain.to_vec();
+        buf.resize(plain.len() + 16, 0);
+        let ciphertext = cbc::Encryptor::<aes::Aes128>::new(&chromium_key().into(), &iv.into())
+            .encrypt_padded_mut::<Pkcs7>(&mut buf, plain.len())
+            .expect("failed to encrypt fixture");
+        let mut encrypted = scheme.to_vec();
+        encrypted.extend(ciphertext);
+
+        let path = fixtures.path(name);
+        let conn = Connection::open(&path).expect("failed to create fixture");
+        conn.execute_batch(concat!(
+            "CREATE TABLE meta (key TEXT PRIMARY KEY, value TEXT);",
+            "CREATE TABLE cookies (host_key TEXT, name TEXT, value TEXT, encrypted_value BLOB, last_access_utc INTEGER);",
+        )).expect("failed to create tables");
+        conn.execute("INSERT INTO meta (key, value) VALUES ('version', ?1)", [version.to_string()])
+            .expect("failed to fill fixture");
+        conn.execute(
+            "INSERT INTO cookies VALUES ('.adventofcode.com', 'session', '', ?1, 1)",
+            [encrypted],
+        ).expect("failed to fill fixture");
+        path
+    }
+
+    #[test]
+    fn test_firefox() {
+        let fixtures = Fixtures::new("firefox");
+        let path = firefox_fixture(&fixtures, "firefox.sqlite");
+        let cookie = Browser::Firefox.read_session_cookie(&path).expect("failed to read cookie");
+        assert_eq!(Some("abc123".to_string()), cookie);
+    }
+
+    #[test]
+    fn test_chromium() {
+        let fixtures = Fixtures::new("chromium");
+        let path = chromium_fixture(&fixtures, "chromium-v23.sqlite", 23, b"v10", b"abc123");
+        let cookie = Browser::Chromium.read_session_cookie(&path).expect("failed to read cookie");
+        assert_eq!(Some("abc123".to_string()), cookie);
+
+        // Newer databases prefix the value with a hash of the domain.
+        let mut plain = vec![0u8; 32];
+        plain.extend(b"abc123");
+        let path = chromium_fixture(&fixtures, "chromium-v24.sqlite", 24, b"v10", &plain);
+        let cookie = Browser::Chromium.read_session_cookie(&path).expect("failed to read cookie");
+        assert_eq!(Some("abc123".to_string()), cookie);
+    }
+
+    #[test]
+    fn test_chromium_keyring() {
+        let fixtures = Fixtures::new("chromium-keyring");
+        let path = chromium_fixture(&fixtures, "chromium-v11.sqlite", 23, b"v11", b"abc123");
+        let err = Browser::Chromium.read_session_cookie(&path).expect_err("keyring cookies are not supported");
+        assert!(format!("{:#}", err).contains("system keyring"), "{:#}", err);
+    }
+
+    #[test]
+    fn test_skips_unreadable_profiles() {
+        let fixtures = Fixtures::new("skip");
+        let broken = fixtures.path("broken.sqlite");
+        fs::write(&broken, "not a database").expect("failed to write fixture");
+        let missing = fixtures.path("missing.sqlite");
+        let path = firefox_fixture(&fixtures, "firefox.sqlite");
+
+        let cookie = Browser::Firefox.first_session_cookie(&[broken.clone(), missing, path]);
+        assert_eq!("abc123", cookie.expect("failed to find cookie"));
+        assert!(Browser::Firefox.first_session_cookie(&[broken]).is_err());
+    }
+}
@@ -1,21 +1,77 @@
 use std::io::Write;
-use std::fs::{self, File};
-use minreq;
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
 use anyhow::{anyhow, Result, Context};
 
+mod browser;
+mod provider;
+
+pub use browser::Browser;
+pub use provider::{now, provider_by_name, AdventOfCode, DayStatus, PuzzleProvider, Submission, DEFAULT_PROVIDER};
+
 pub const DEFAULT_INPUT_PATH: &str = "input";
+pub const DEFAULT_YEAR: u16 = 2023;
+pub const DEFAULT_SESSION_PATH: &str = ".session";
+
+/// Reads the session cookie saved by `entry login`, if there is one.
+pub fn read_session(path: &str) -> Option<String> {
+    let cookie = fs::read_to_string(path).ok()?;
+    let cookie = cookie.trim();
+    if cookie.is_empty() {
+        None
+    } else {
+        Some(cookie.to_string())
+    }
+}
+
+/// Saves the session cookie so later runs do not have to ask for it.
+/// The cookie logs in as the user, so on Unix only they can read the
+/// file, even if it already existed with wider permissions.
+pub fn save_session(path: &str, cookie: &str) -> Result<()> {
+    let context = || format!("Could not write session file: {}", path);
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).with_context(context)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(fs::Permissions::from_mode(0o600)).with_context(context)?;
+    }
+    writeln!(file, "{cookie}").with_context(context)
+}
+
+/// Returns the path of the input file for the given day,
+/// which is stored under a directory per year:
+/// `{input_dir}/{year}/{day}.txt`.
+pub fn input_path(input_dir: impl AsRef<Path>, year: u16, day: usize) -> PathBuf {
+    input_dir.as_ref().join(year.to_string()).join(format!("{day}.txt"))
+}
+
+pub fn get_input(provider: &dyn PuzzleProvider, day: usize, year: u16, cookie: &str) -> Result<String> {
+    get_page(provider, &provider.input_url(year, day), cookie)
+}
+
+/// Downloads the event page, which shows the stars collected so far.
+pub fn get_event_page(provider: &dyn PuzzleProvider, year: u16, cookie: &str) -> Result<String> {
+    get_page(provider, &provider.event_url(year), cookie)
+}
 
-pub fn get_input(day: usize, year: &String, cookie: &String) -> Result<String> {
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+fn get_page(provider: &dyn PuzzleProvider, url: &str, cookie: &str) -> Result<String> {
+    let (name, value) = provider.auth_header(cookie);
 
-    let response = minreq::get(&url)
-        .with_header("Cookie", format!("session={cookie}"))
+    let response = minreq::get(url)
+        .with_header(name, value)
         .send()
         .with_context(|| format!("Could not send request: {}", url))?;
 
     let body = response
         .as_str()
-        .context(format!("Could not read response"))?;
+        .context("Could not read response")?;
 
     if response.status_code != 200 {
         return Err(anyhow!(format!("Response status code {}", response.status_code)));
@@ -26,16 +82,25 @@ pub fn get_input(day: usize, year: &String, cookie: &String) -> Result<String> {
     Ok(body.to_string())
 }
 
-pub fn get_all_inputs(days: Vec<usize>, year: &String, cookie: &String, output_dir: &String) -> Result<()> {
+pub fn get_all_inputs(provider: &dyn PuzzleProvider, days: Vec<usize>, year: u16, cookie: &str, output_dir: &str) -> Result<()> {
     let mut all_ok = true;
 
-    fs::create_dir_all(&output_dir)
-        .with_context(|| format!("Could not create output directory: {}", output_dir))?;
+    let year_dir = Path::new(output_dir).join(year.to_string());
+    fs::create_dir_all(&year_dir)
+        .with_context(|| format!("Could not create output directory: {}", year_dir.display()))?;
+
+    println!("Downloading inputs for {year}:");
 
-    println!("Downloading inputs:");
+    let now = now();
 
     'next_day: for day in days {
-        let input = match get_input(day, &year, &cookie) {
+        if !provider.is_unlocked(year, day, now) {
+            eprintln!("    Day {:2}: Not unlocked yet", day);
+            all_ok = false;
+            continue 'next_day;
+        }
+
+        let input = match get_input(provider, day, year, cookie) {
             Ok(input) => input,
             Err(e) => {
                 eprintln!("    Day {:2}: {}", day, e);
@@ -44,13 +109,13 @@ pub fn get_all_inputs(days: Vec<usize>, year: &String, cookie: &String, output_d
             },
         };
 
-        let file_path = format!("{output_dir}/{day}.txt");
+        let file_path = input_path(output_dir, year, day);
         let mut file = File::create(&file_path)
-            .with_context(|| format!("Could not create file: {}", file_path))?;
+            .with_context(|| format!("Could not create file: {}", file_path.display()))?;
 
         file.write_all(input.as_bytes()).context("Could not write file")?;
 
-        println!("    Day {:2}: Created file {}", day, file_path);
+        println!("    Day {:2}: Created file {}", day, file_path.display());
     }
 
     if all_ok {
@@ -62,3 +127,53 @@ pub fn get_all_inputs(days: Vec<usize>, year: &String, cookie: &String, output_d
     Ok(())
 }
 
+/// Submits an answer for one part of a puzzle and returns how the
+/// provider judged it.
+pub fn submit_answer(provider: &dyn PuzzleProvider, day: usize, year: u16, part: u8, answer: &str, cookie: &str) -> Result<Submission> {
+    let (url, body) = provider.submission_request(year, day, part, answer);
+    let (name, value) = provider.auth_header(cookie);
+
+    let response = minreq::post(&url)
+        .with_header(name, value)
+        .with_header("Content-Type", "application/x-www-form-urlencoded")
+        .with_body(body)
+        .send()
+        .with_context(|| format!("Could not send request: {}", url))?;
+
+    let body = response
+        .as_str()
+        .context("Could not read response")?;
+
+    if response.status_code != 200 {
+        return Err(anyhow!(format!("Response status code {}", response.status_code)));
+    }
+
+    Ok(provider.parse_submission(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+    use super::{read_session, save_session};
+
+    #[cfg(unix)]
+    #[test]
+    fn test_save_session_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = env::temp_dir().join(format!("aoc-session-{}", process::id()));
+        let path_str = path.to_str().expect("temporary path is not UTF-8");
+        // An existing world-readable file is made private too.
+        fs::write(&path, "old\n").expect("failed to create session file");
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).expect("failed to set permissions");
+
+        let saved = save_session(path_str, "abc");
+        let mode = fs::metadata(&path).map(|metadata| metadata.permissions().mode() & 0o777);
+        let cookie = read_session(path_str);
+        let _ = fs::remove_file(&path);
+
+        saved.expect("failed to save session");
+        assert_eq!(0o600, mode.expect("failed to read permissions"));
+        assert_eq!(Some("abc".to_string()), cookie);
+    }
+}
@@ -1,13 +1,14 @@
 use std::io;
 use std::env;
-use std::mem;
-use input_downloader::{get_all_inputs, DEFAULT_INPUT_PATH};
+use input_downloader::{get_all_inputs, provider_by_name, read_session};
+use input_downloader::{DEFAULT_INPUT_PATH, DEFAULT_PROVIDER, DEFAULT_SESSION_PATH, DEFAULT_YEAR};
 use anyhow::{anyhow, Result, Context};
 
 fn main() -> Result<()> {
     let mut cookie = String::new();
     let mut days: Vec<usize> = Vec::new();
-    let mut year = "2023".to_string();
+    let mut year = DEFAULT_YEAR;
+    let mut provider = DEFAULT_PROVIDER.to_string();
 
     let mut output_dir: Option<String> = None;
 
@@ -18,7 +19,11 @@ fn main() -> Result<()> {
                 cookie = args.next().context(format!("Expected string after argument: {}", arg))?;
             },
             "--year" | "-year" | "-y" => {
-                year = args.next().context(format!("Expected string after argument: {}", arg))?;
+                let arg = args.next().context(format!("Expected string after argument: {}", arg))?;
+                year = arg.parse().with_context(|| format!("Failed to parse year: {}", arg))?;
+            },
+            "--provider" | "-provider" | "-p" => {
+                provider = args.next().context(format!("Expected string after argument: {}", arg))?;
             },
             "--day" | "-day" | "-d" => {
                 let arg = args
@@ -33,7 +38,7 @@ fn main() -> Result<()> {
             "--output_dir" | "-output_dir" | "--dir" | "-dir" => {
                 let new = args.next().context(format!("Expected string after argument: {}", arg))?;
                 
-                if let Some(old) = mem::replace(&mut output_dir, Some(new.clone())) {
+                if let Some(old) = output_dir.replace(new.clone()) {
                     return Err(anyhow!(format!("Duplicate output directory flag: {} and {}", new, old)));
                 }
             },
@@ -41,17 +46,23 @@ fn main() -> Result<()> {
         }
     }
 
+    if cookie.is_empty() {
+        cookie = read_session(DEFAULT_SESSION_PATH).unwrap_or_default();
+    }
+
     if cookie.is_empty() {
         println!("Please enter session cookie:");
         io::stdin().read_line(&mut cookie).context("Could not read input")?;
         cookie = cookie.trim().to_string();
     }
 
+    let provider = provider_by_name(&provider)?;
+
     let output_dir = output_dir.unwrap_or(DEFAULT_INPUT_PATH.to_string());
-    if days.len() == 0 {
-        get_all_inputs((1..=25).collect(), &year, &cookie, &output_dir)?;
+    if days.is_empty() {
+        get_all_inputs(provider.as_ref(), provider.days(year), year, &cookie, &output_dir)?;
     } else {
-        get_all_inputs(days, &year, &cookie, &output_dir)?;
+        get_all_inputs(provider.as_ref(), days, year, &cookie, &output_dir)?;
     }
 
     Ok(())
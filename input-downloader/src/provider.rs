@@ -0,0 +1,230 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{anyhow, Result};
+
+/// A site that publishes a daily puzzle with a per-user input,
+/// such as Advent of Code.
+pub trait PuzzleProvider {
+    /// Short name used to select the provider on the command line.
+    fn name(&self) -> &'static str;
+
+    /// Days that make up the event for the given year.
+    fn days(&self, year: u16) -> Vec<usize>;
+
+    /// URL from which the personal puzzle input is downloaded.
+    fn input_url(&self, year: u16, day: usize) -> String;
+
+    /// HTTP header that authenticates a request with the given token,
+    /// returned as a `(name, value)` pair.
+    fn auth_header(&self, token: &str) -> (&'static str, String);
+
+    /// Unix timestamp (in seconds) at which the puzzle is unlocked.
+    fn unlocks_at(&self, year: u16, day: usize) -> u64;
+
+    /// URL and form body used to submit an answer for a part of a puzzle.
+    fn submission_request(&self, year: u16, day: usize, part: u8, answer: &str) -> (String, String);
+
+    /// Interprets the response body returned after submitting an answer.
+    fn parse_submission(&self, body: &str) -> Submission;
+
+    /// URL of the event page, which shows the progress of the user.
+    fn event_url(&self, year: u16) -> String;
+
+    /// Reads the status of every day shown on the event page. Days
+    /// that do not appear on the page are not unlocked yet.
+    fn parse_event_page(&self, html: &str) -> Vec<(usize, DayStatus)>;
+
+    /// Returns true if the puzzle is unlocked at the given Unix timestamp.
+    fn is_unlocked(&self, year: u16, day: usize, now: u64) -> bool {
+        now >= self.unlocks_at(year, day)
+    }
+}
+
+/// The outcome of submitting an answer.
+#[derive(Debug, PartialEq)]
+pub enum Submission {
+    Correct,
+    Incorrect,
+    /// The answer was submitted too soon after a previous one.
+    TooSoon,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+/// The progress of the user on a single day, as shown on the event page.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayStatus {
+    Locked,
+    Unlocked,
+    OneStar,
+    TwoStars,
+}
+
+/// The puzzles at <https://adventofcode.com>.
+pub struct AdventOfCode;
+
+impl PuzzleProvider for AdventOfCode {
+    fn name(&self) -> &'static str {
+        "aoc"
+    }
+
+    fn days(&self, year: u16) -> Vec<usize> {
+        // Starting with 2025, the event only runs for twelve days.
+        if year >= 2025 {
+            (1..=12).collect()
+        } else {
+            (1..=25).collect()
+        }
+    }
+
+    fn input_url(&self, year: u16, day: usize) -> String {
+        format!("https://adventofcode.com/{year}/day/{day}/input")
+    }
+
+    fn auth_header(&self, token: &str) -> (&'static str, String) {
+        ("Cookie", format!("session={token}"))
+    }
+
+    /// Puzzles unlock at midnight EST (UTC-5) on the given day of December.
+    fn unlocks_at(&self, year: u16, day: usize) -> u64 {
+        days_since_epoch(year as i64, 12, day as i64) as u64 * 86_400 + 5 * 3_600
+    }
+
+    fn submission_request(&self, year: u16, day: usize, part: u8, answer: &str) -> (String, String) {
+        let url = format!("https://adventofcode.com/{year}/day/{day}/answer");
+        (url, format!("level={part}&answer={}", percent_encode(answer)))
+    }
+
+    fn parse_submission(&self, body: &str) -> Submission {
+        if body.contains("That's the right answer") {
+            Submission::Correct
+        } else if body.contains("That's not the right answer") {
+            Submission::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            Submission::TooSoon
+        } else if body.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else {
+            Submission::Unknown
+        }
+    }
+
+    fn event_url(&self, year: u16) -> String {
+        format!("https://adventofcode.com/{year}")
+    }
+
+    /// Unlocked days are links of the form
+    /// `<a aria-label="Day 1, two stars" href="/2023/day/1" ...>`,
+    /// while locked days have no label at all.
+    fn parse_event_page(&self, html: &str) -> Vec<(usize, DayStatus)> {
+        let mut result = Vec::new();
+        for (i, pattern) in html.match_indices("aria-label=\"Day ") {
+            let rest = &html[i + pattern.len()..];
+            let Some(end) = rest.find('"') else { continue };
+            let label = &rest[..end];
+            let (day, stars) = label.split_once(',').unwrap_or((label, ""));
+            let Ok(day) = day.trim().parse::<usize>() else { continue };
+
+            let status = match stars.trim() {
+                "two stars" => DayStatus::TwoStars,
+                "one star" => DayStatus::OneStar,
+                _ => DayStatus::Unlocked,
+            };
+            result.push((day, status));
+        }
+        result
+    }
+}
+
+pub const DEFAULT_PROVIDER: &str = "aoc";
+
+/// Looks up a provider by its command-line name.
+pub fn provider_by_name(name: &str) -> Result<Box<dyn PuzzleProvider>> {
+    match name {
+        "aoc" => Ok(Box::new(AdventOfCode)),
+        _ => Err(anyhow!(format!("Unknown puzzle provider: {}", name))),
+    }
+}
+
+/// Returns the current Unix timestamp in seconds.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Encodes a value for a form body, keeping only the characters that
+/// never need escaping, so that an answer containing `&`, `=`, `+` or a
+/// space is submitted as is.
+fn percent_encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => result.push(byte as char),
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    result
+}
+
+/// Number of days from 1970-01-01 to the given date in the proleptic
+/// Gregorian calendar (Howard Hinnant's `days_from_civil`).
+fn days_since_epoch(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AdventOfCode, DayStatus, PuzzleProvider, Submission};
+
+    #[test]
+    fn test_unlocks_at() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(1701406800, AdventOfCode.unlocks_at(2023, 1));
+        assert!(!AdventOfCode.is_unlocked(2023, 2, 1701406800));
+        assert!(AdventOfCode.is_unlocked(2023, 2, 1701406800 + 86_400));
+    }
+
+    #[test]
+    fn test_submission_request() {
+        let (url, body) = AdventOfCode.submission_request(2023, 1, 2, "54845");
+        assert_eq!("https://adventofcode.com/2023/day/1/answer", url);
+        assert_eq!("level=2&answer=54845", body);
+
+        let (_, body) = AdventOfCode.submission_request(2023, 1, 1, "a&b=c+d e/é");
+        assert_eq!("level=1&answer=a%26b%3Dc%2Bd%20e%2F%C3%A9", body);
+    }
+
+    #[test]
+    fn test_parse_submission() {
+        let body = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+        assert_eq!(Submission::Correct, AdventOfCode.parse_submission(body));
+
+        let body = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        assert_eq!(Submission::Incorrect, AdventOfCode.parse_submission(body));
+    }
+
+    #[test]
+    fn test_parse_event_page() {
+        let html = concat!(
+            "<a aria-label=\"Day 1, two stars\" href=\"/2023/day/1\" class=\"calendar-day1 calendar-verycomplete\">",
+            "<a aria-label=\"Day 2, one star\" href=\"/2023/day/2\" class=\"calendar-day2 calendar-complete\">",
+            "<a aria-label=\"Day 3\" href=\"/2023/day/3\" class=\"calendar-day3\">",
+            "<span aria-hidden=\"true\" class=\"calendar-day4\">",
+        );
+
+        let days = AdventOfCode.parse_event_page(html);
+        assert_eq!(vec![
+            (1, DayStatus::TwoStars),
+            (2, DayStatus::OneStar),
+            (3, DayStatus::Unlocked),
+        ], days);
+    }
+}